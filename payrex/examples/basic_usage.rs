@@ -6,18 +6,19 @@
 //! - Retrieve a payment intent
 //! - Handle errors
 //!
-//! Run with: cargo run --example basic_usage
+//! Run with: PAYREX_API_KEY=sk_test_... cargo run --example basic_usage
 
 use payrex::resources::payment_intents::CreatePaymentIntent;
 use payrex::types::{CaptureMethod, Currency, Metadata, PaymentMethod};
-use payrex::{Client, Error, ErrorKind};
+use payrex::{Client, Config, Error, ErrorKind};
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let api_key =
-        std::env::var("PAYREX_API_KEY").unwrap_or_else(|_| "your_secret_api_key".to_string());
+    // Reads PAYREX_API_KEY and the other PAYREX_* variables. Fails if PAYREX_API_KEY is missing or
+    // any of the variables is invalid.
+    let config = Config::from_env()?;
 
-    let client = Client::new(api_key);
+    let client = Client::with_config(config)?;
 
     println!("PayRex SDK Example - Basic Usage\n");
     println!("1. Creating a payment intent...");
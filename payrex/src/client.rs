@@ -4,9 +4,9 @@
 //! authentication, request/response processing, and error handling.
 
 use crate::{
-    Result,
-    config::Config,
-    http::HttpClient,
+    Error, Result,
+    config::{Config, FORBID_LIVE_ENV, Mode, live_mode_forbidden},
+    http::{DryRunCall, HttpClient},
     resources::{
         BillingStatementLineItems, BillingStatements, CheckoutSessions, Customers, PaymentIntents,
         Payments, Payouts, Refunds, Webhooks,
@@ -43,25 +43,55 @@ impl Client {
     /// Creates a new [`Client`] instance with a specified API key.
     ///
     /// Panics if the API key is invalid. For fallible construction, use [`Client::try_new`].
+    ///
+    /// **Note**: this constructor bypasses the `PAYREX_FORBID_LIVE` safety guard so that it never
+    /// panics because of the environment. Use [`Client::try_new`] or [`Client::with_config`] to
+    /// have live mode keys refused while the guard is set.
     #[must_use]
     pub fn new(api_key: impl Into<String>) -> Self {
-        Self::try_new(api_key).expect("Failed to create PayRex client")
+        Config::new(api_key)
+            .and_then(Self::build)
+            .expect("Failed to create PayRex client")
     }
 
     /// Creates a new [`Client`] instance without checking if the API key is valid.
+    ///
+    /// Fails for `sk_live_` keys while the `PAYREX_FORBID_LIVE` safety guard is set.
     pub fn try_new(api_key: impl Into<String>) -> Result<Self> {
         let config = Config::new(api_key)?;
         Self::with_config(config)
     }
 
     /// Attach a [`Config`] to the client.
+    ///
+    /// Fails for configurations with an `sk_live_` key while the `PAYREX_FORBID_LIVE` safety
+    /// guard is set.
     pub fn with_config(config: Config) -> Result<Self> {
+        ensure_live_allowed(&config, live_mode_forbidden())?;
+        Self::build(config)
+    }
+
+    fn build(config: Config) -> Result<Self> {
         let http = HttpClient::new(config)?;
         Ok(Self {
             http: Arc::new(http),
         })
     }
 
+    /// Returns the mutating requests recorded so far by a client in
+    /// [`ExecutionMode::DryRun`](crate::ExecutionMode::DryRun), in the order they were made.
+    #[must_use]
+    pub fn dry_run_report(&self) -> Vec<DryRunCall> {
+        self.http.dry_run_calls()
+    }
+
+    /// Returns and clears the mutating requests recorded so far by a client in
+    /// [`ExecutionMode::DryRun`](crate::ExecutionMode::DryRun).
+    #[must_use]
+    pub fn take_dry_run_report(&self) -> Vec<DryRunCall> {
+        self.http.take_dry_run_calls()
+    }
+
     /// Payment Intents API Endpoints.
     #[must_use]
     pub fn payment_intents(&self) -> PaymentIntents {
@@ -125,6 +155,91 @@ impl std::fmt::Debug for Client {
     }
 }
 
+/// A pair of clients for working with test mode and live mode side by side.
+///
+/// Each configuration must be in its own mode and carry an API key with the matching prefix. While
+/// the `PAYREX_FORBID_LIVE` safety guard is set, the live client is never built and
+/// [`ClientSet::live`] returns an error.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use payrex::{ClientSet, Mode};
+///
+/// # fn main() -> Result<(), payrex::Error> {
+/// let clients = ClientSet::new("sk_test_...", "sk_live_...")?;
+///
+/// let test = clients.test();
+/// let live = clients.for_mode(Mode::Live)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ClientSet {
+    test: Client,
+    live: Option<Client>,
+}
+
+impl ClientSet {
+    /// Creates a new [`ClientSet`] from a test mode and a live mode API key.
+    pub fn new(test_api_key: impl Into<String>, live_api_key: impl Into<String>) -> Result<Self> {
+        Self::from_configs(Config::new(test_api_key)?, Config::new(live_api_key)?)
+    }
+
+    /// Creates a new [`ClientSet`] from a test mode and a live mode [`Config`].
+    pub fn from_configs(test: Config, live: Config) -> Result<Self> {
+        Self::build(test, live, live_mode_forbidden())
+    }
+
+    fn build(test: Config, live: Config, forbid_live: bool) -> Result<Self> {
+        test.ensure_mode(Mode::Test)?;
+        live.ensure_mode(Mode::Live)?;
+
+        let test = Client::build(test)?;
+        let live = if forbid_live {
+            None
+        } else {
+            Some(Client::build(live)?)
+        };
+
+        Ok(Self { test, live })
+    }
+
+    /// Returns the test mode client.
+    #[must_use]
+    pub const fn test(&self) -> &Client {
+        &self.test
+    }
+
+    /// Returns the live mode client, or an error if live mode is forbidden.
+    pub fn live(&self) -> Result<&Client> {
+        self.live.as_ref().ok_or_else(live_mode_forbidden_error)
+    }
+
+    /// Returns the client for the given [`Mode`].
+    pub fn for_mode(&self, mode: Mode) -> Result<&Client> {
+        match mode {
+            Mode::Test => Ok(self.test()),
+            Mode::Live => self.live(),
+        }
+    }
+}
+
+/// Refuses configurations carrying a live mode key while live mode is forbidden. Keys without the
+/// `sk_live_` prefix, such as placeholders in tests, are always allowed.
+fn ensure_live_allowed(config: &Config, forbid_live: bool) -> Result<()> {
+    if forbid_live && config.api_key().starts_with(Mode::Live.key_prefix()) {
+        return Err(live_mode_forbidden_error());
+    }
+    Ok(())
+}
+
+fn live_mode_forbidden_error() -> Error {
+    Error::Config(format!(
+        "Live mode clients are forbidden while {FORBID_LIVE_ENV} is set"
+    ))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -161,4 +276,46 @@ mod tests {
 
         assert!(std::sync::Arc::ptr_eq(&client.http, &cloned.http));
     }
+
+    #[test]
+    fn test_client_set_build() {
+        let test = Config::new("sk_test_abc123").unwrap();
+        let live = Config::new("sk_live_abc123").unwrap();
+        let clients = ClientSet::build(test, live, false).unwrap();
+
+        assert!(clients.for_mode(Mode::Test).is_ok());
+        assert!(clients.for_mode(Mode::Live).is_ok());
+    }
+
+    #[test]
+    fn test_client_set_forbid_live() {
+        let test = Config::new("sk_test_abc123").unwrap();
+        let live = Config::new("sk_live_abc123").unwrap();
+        let clients = ClientSet::build(test, live, true).unwrap();
+
+        assert!(clients.for_mode(Mode::Test).is_ok());
+        assert!(matches!(clients.live(), Err(Error::Config(_))));
+    }
+
+    #[test]
+    fn test_ensure_live_allowed() {
+        let live = Config::new("sk_live_abc123").unwrap();
+        let test = Config::new("sk_test_abc123").unwrap();
+        let placeholder = Config::new("test_key").unwrap();
+
+        assert!(ensure_live_allowed(&live, false).is_ok());
+        assert!(matches!(
+            ensure_live_allowed(&live, true),
+            Err(Error::Config(_))
+        ));
+        assert!(ensure_live_allowed(&test, true).is_ok());
+        assert!(ensure_live_allowed(&placeholder, true).is_ok());
+    }
+
+    #[test]
+    fn test_client_set_swapped_keys() {
+        let test = Config::new("sk_live_abc123").unwrap();
+        let live = Config::new("sk_test_abc123").unwrap();
+        assert!(ClientSet::build(test, live, false).is_err());
+    }
 }
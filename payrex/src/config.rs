@@ -2,9 +2,112 @@
 //!
 //! This module provides configuration options for customizing the behavior
 //! of the PayRex client, including timeouts, retries, and API endpoints.
+//!
+//! A configuration can be built in code with [`ConfigBuilder`], read from `PAYREX_*` environment
+//! variables with [`Config::from_env`], or loaded from a TOML file with [`Config::from_file`] and
+//! [`Config::from_file_profile`].
 
 use crate::{API_BASE_URL, Error, Result};
-use std::time::Duration;
+use reqwest::{Certificate, Identity, Proxy};
+use serde::Deserialize;
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    path::{Path, PathBuf},
+    str::FromStr,
+    time::Duration,
+};
+
+/// Name of the environment variable that, when set to a truthy value, forbids building clients
+/// that operate in live mode.
+pub const FORBID_LIVE_ENV: &str = "PAYREX_FORBID_LIVE";
+
+/// The mode a client operates in. PayRex keeps test and live data separate, and the mode is
+/// determined by the prefix of the API key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Mode {
+    /// Test mode, used with `sk_test_` keys.
+    Test,
+
+    /// Live mode, used with `sk_live_` keys.
+    Live,
+}
+
+impl Mode {
+    /// Returns the API key prefix expected for the mode.
+    #[must_use]
+    pub const fn key_prefix(self) -> &'static str {
+        match self {
+            Self::Test => "sk_test_",
+            Self::Live => "sk_live_",
+        }
+    }
+
+    /// Returns the string slice representation of the mode.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Test => "test",
+            Self::Live => "live",
+        }
+    }
+}
+
+impl Display for Mode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Controls which requests a client is allowed to send.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecutionMode {
+    /// Every request is sent.
+    #[default]
+    Normal,
+
+    /// Only `GET` requests are sent. Any other request fails with [`Error::ReadOnly`] before it
+    /// reaches the network.
+    ReadOnly,
+
+    /// `GET` requests are sent, while mutating requests are logged with their encoded form body,
+    /// recorded in the dry-run report of the client, and fail with [`Error::DryRun`].
+    DryRun,
+}
+
+impl ExecutionMode {
+    /// Returns the string slice representation of the execution mode.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Normal => "normal",
+            Self::ReadOnly => "read_only",
+            Self::DryRun => "dry_run",
+        }
+    }
+}
+
+impl Display for ExecutionMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for ExecutionMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "normal" => Ok(Self::Normal),
+            "read_only" => Ok(Self::ReadOnly),
+            "dry_run" => Ok(Self::DryRun),
+            _ => Err(format!(
+                "expected `normal`, `read_only` or `dry_run`, got `{s}`"
+            )),
+        }
+    }
+}
 
 /// Configuration for the PayRex client.
 ///
@@ -18,6 +121,14 @@ pub struct Config {
     pub(crate) retry_delay: Duration,
     pub(crate) user_agent: String,
     pub(crate) test_mode: bool,
+    pub(crate) proxy: Option<String>,
+    pub(crate) no_proxy: Option<String>,
+    pub(crate) root_certificates: Vec<Certificate>,
+    pub(crate) identity: Option<Identity>,
+    pub(crate) pool_idle_timeout: Option<Duration>,
+    pub(crate) pool_max_idle_per_host: Option<usize>,
+    pub(crate) check_livemode: bool,
+    pub(crate) execution_mode: ExecutionMode,
 }
 
 impl Config {
@@ -30,7 +141,7 @@ impl Config {
             return Err(Error::InvalidApiKey("API key cannot be empty".to_string()));
         }
 
-        let test_mode = api_key.starts_with("sk_test_");
+        let test_mode = api_key.starts_with(Mode::Test.key_prefix());
 
         Ok(Self {
             api_key,
@@ -40,6 +151,14 @@ impl Config {
             retry_delay: Duration::from_millis(500),
             user_agent: format!("payrex-rust/{}", crate::VERSION),
             test_mode,
+            proxy: None,
+            no_proxy: None,
+            root_certificates: Vec::new(),
+            identity: None,
+            pool_idle_timeout: None,
+            pool_max_idle_per_host: None,
+            check_livemode: cfg!(debug_assertions),
+            execution_mode: ExecutionMode::Normal,
         })
     }
 
@@ -49,6 +168,24 @@ impl Config {
         ConfigBuilder::default()
     }
 
+    /// Creates a new [`Config`] from `PAYREX_*` environment variables. See
+    /// [`ConfigBuilder::from_env`] for the list of variables that are read.
+    pub fn from_env() -> Result<Self> {
+        ConfigBuilder::from_env()?.build()
+    }
+
+    /// Creates a new [`Config`] from the top-level settings of a TOML file. See
+    /// [`ConfigBuilder::from_file`] for the file format.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self> {
+        ConfigBuilder::from_file(path, None)?.build()
+    }
+
+    /// Creates a new [`Config`] from a named profile of a TOML file, such as `test` or `live`.
+    /// Settings of the profile override the top-level settings of the file.
+    pub fn from_file_profile(path: impl AsRef<Path>, profile: &str) -> Result<Self> {
+        ConfigBuilder::from_file(path, Some(profile))?.build()
+    }
+
     /// Returns the API key as a string slice.
     #[must_use]
     pub fn api_key(&self) -> &str {
@@ -90,6 +227,73 @@ impl Config {
     pub const fn is_test_mode(&self) -> bool {
         self.test_mode
     }
+
+    /// Returns the [`Mode`] of the configuration.
+    #[must_use]
+    pub const fn mode(&self) -> Mode {
+        if self.test_mode {
+            Mode::Test
+        } else {
+            Mode::Live
+        }
+    }
+
+    /// Checks that the configuration is in the expected mode and that its API key carries the
+    /// matching prefix.
+    pub fn ensure_mode(&self, mode: Mode) -> Result<()> {
+        if self.mode() != mode {
+            return Err(Error::Config(format!(
+                "Expected a {mode} mode configuration, got {}",
+                self.mode()
+            )));
+        }
+
+        if !self.api_key.starts_with(mode.key_prefix()) {
+            return Err(Error::InvalidApiKey(format!(
+                "A {mode} mode API key must start with `{}`",
+                mode.key_prefix()
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Returns the proxy URL all requests are routed through, if any.
+    #[must_use]
+    pub fn proxy(&self) -> Option<&str> {
+        self.proxy.as_deref()
+    }
+
+    /// Returns the comma-separated list of hosts that bypass the proxy, if any.
+    #[must_use]
+    pub fn no_proxy(&self) -> Option<&str> {
+        self.no_proxy.as_deref()
+    }
+
+    /// Returns the duration an idle pooled connection is kept alive.
+    #[must_use]
+    pub const fn pool_idle_timeout(&self) -> Option<Duration> {
+        self.pool_idle_timeout
+    }
+
+    /// Returns the maximum number of idle connections kept per host.
+    #[must_use]
+    pub const fn pool_max_idle_per_host(&self) -> Option<usize> {
+        self.pool_max_idle_per_host
+    }
+
+    /// Returns `true` if responses are checked to have a `livemode` flag matching the [`Mode`]
+    /// of the configuration.
+    #[must_use]
+    pub const fn check_livemode(&self) -> bool {
+        self.check_livemode
+    }
+
+    /// Returns the [`ExecutionMode`] of the configuration.
+    #[must_use]
+    pub const fn execution_mode(&self) -> ExecutionMode {
+        self.execution_mode
+    }
 }
 
 /// Builder for [`Config`].
@@ -104,6 +308,14 @@ pub struct ConfigBuilder {
     retry_delay: Option<Duration>,
     user_agent: Option<String>,
     test_mode: bool,
+    proxy: Option<String>,
+    no_proxy: Option<String>,
+    root_certificates: Vec<Vec<u8>>,
+    identity: Option<(Vec<u8>, Vec<u8>)>,
+    pool_idle_timeout: Option<Duration>,
+    pool_max_idle_per_host: Option<usize>,
+    check_livemode: Option<bool>,
+    execution_mode: ExecutionMode,
 }
 
 impl ConfigBuilder {
@@ -113,6 +325,74 @@ impl ConfigBuilder {
         Self::default()
     }
 
+    /// Returns a new [`ConfigBuilder`] pre-filled from environment variables. Unset or empty
+    /// variables are ignored.
+    ///
+    /// | Variable | Setting |
+    /// | --- | --- |
+    /// | `PAYREX_API_KEY` | API key |
+    /// | `PAYREX_API_BASE_URL` | API base URL |
+    /// | `PAYREX_TIMEOUT` | Request timeout in seconds |
+    /// | `PAYREX_MAX_RETRIES` | Maximum retries |
+    /// | `PAYREX_RETRY_DELAY_MS` | Retry delay in milliseconds |
+    /// | `PAYREX_USER_AGENT` | User agent |
+    /// | `PAYREX_TEST_MODE` | Test mode (`true`/`false`, `1`/`0`) |
+    /// | `PAYREX_PROXY` | Proxy URL |
+    /// | `PAYREX_NO_PROXY` | Hosts that bypass the proxy |
+    /// | `PAYREX_ROOT_CERTIFICATES` | Paths of PEM root certificates, separated like `PATH` |
+    /// | `PAYREX_POOL_IDLE_TIMEOUT` | Idle pooled connection timeout in seconds |
+    /// | `PAYREX_POOL_MAX_IDLE_PER_HOST` | Maximum idle connections per host |
+    /// | `PAYREX_CHECK_LIVEMODE` | Livemode check of responses (`true`/`false`, `1`/`0`) |
+    /// | `PAYREX_EXECUTION_MODE` | `normal`, `read_only` or `dry_run` |
+    pub fn from_env() -> Result<Self> {
+        Self::from_lookup(|key| std::env::var(key).ok())
+    }
+
+    fn from_lookup(lookup: impl Fn(&str) -> Option<String>) -> Result<Self> {
+        Settings::from_lookup(lookup)?.apply(Self::default())
+    }
+
+    /// Returns a new [`ConfigBuilder`] pre-filled from a TOML file. When `profile` is set, the
+    /// settings under `[profiles.<profile>]` override the top-level settings.
+    ///
+    /// Every key is optional and mirrors the environment variables of
+    /// [`ConfigBuilder::from_env`]. Relative certificate paths are resolved against the directory
+    /// of the file.
+    ///
+    /// ```toml
+    /// timeout = 30
+    /// max_retries = 3
+    ///
+    /// [profiles.test]
+    /// api_key = "sk_test_..."
+    ///
+    /// [profiles.live]
+    /// api_key = "sk_live_..."
+    /// retry_delay_ms = 1000
+    /// proxy = "http://proxy.internal:3128"
+    /// root_certificates = ["corporate-ca.pem"]
+    /// ```
+    pub fn from_file(path: impl AsRef<Path>, profile: Option<&str>) -> Result<Self> {
+        let path = path.as_ref();
+        let contents = std::fs::read_to_string(path).map_err(|e| {
+            Error::Config(format!(
+                "Failed to read config file {}: {e}",
+                path.display()
+            ))
+        })?;
+
+        let mut settings = ConfigFile::parse(&contents)?.settings(profile)?;
+        if let Some(dir) = path.parent() {
+            settings.root_certificates = settings
+                .root_certificates
+                .into_iter()
+                .map(|cert| dir.join(cert))
+                .collect();
+        }
+
+        settings.apply(Self::default())
+    }
+
     /// Sets the API key.
     #[must_use]
     pub fn api_key(mut self, api_key: impl Into<String>) -> Self {
@@ -162,6 +442,73 @@ impl ConfigBuilder {
         self
     }
 
+    /// Sets the URL of an HTTP or HTTPS proxy to route every request through.
+    ///
+    /// Hosts listed in the `NO_PROXY` environment variable bypass the proxy unless
+    /// [`ConfigBuilder::no_proxy`] is set.
+    #[must_use]
+    pub fn proxy(mut self, url: impl Into<String>) -> Self {
+        self.proxy = Some(url.into());
+        self
+    }
+
+    /// Sets the comma-separated list of hosts that bypass the proxy, using the same format as the
+    /// `NO_PROXY` environment variable.
+    #[must_use]
+    pub fn no_proxy(mut self, hosts: impl Into<String>) -> Self {
+        self.no_proxy = Some(hosts.into());
+        self
+    }
+
+    /// Adds PEM-encoded root certificates to trust on top of the default trust store. The PEM may
+    /// contain more than one certificate.
+    #[must_use]
+    pub fn add_root_certificate(mut self, pem: impl Into<Vec<u8>>) -> Self {
+        self.root_certificates.push(pem.into());
+        self
+    }
+
+    /// Sets the PEM-encoded client certificate chain and PKCS#8 private key used for mutual TLS.
+    #[must_use]
+    pub fn identity(
+        mut self,
+        certificate_pem: impl Into<Vec<u8>>,
+        private_key_pem: impl Into<Vec<u8>>,
+    ) -> Self {
+        self.identity = Some((certificate_pem.into(), private_key_pem.into()));
+        self
+    }
+
+    /// Sets the duration an idle pooled connection is kept alive.
+    #[must_use]
+    pub const fn pool_idle_timeout(mut self, timeout: Duration) -> Self {
+        self.pool_idle_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the maximum number of idle connections kept per host.
+    #[must_use]
+    pub const fn pool_max_idle_per_host(mut self, max: usize) -> Self {
+        self.pool_max_idle_per_host = Some(max);
+        self
+    }
+
+    /// Sets whether responses are rejected with [`Error::LivemodeMismatch`] when their `livemode`
+    /// flag contradicts the mode of the client. Enabled by default in debug builds.
+    #[must_use]
+    pub const fn check_livemode(mut self, enabled: bool) -> Self {
+        self.check_livemode = Some(enabled);
+        self
+    }
+
+    /// Sets the [`ExecutionMode`], e.g. to build a read-only client for analytics jobs or a
+    /// dry-run client for migrations.
+    #[must_use]
+    pub const fn execution_mode(mut self, mode: ExecutionMode) -> Self {
+        self.execution_mode = mode;
+        self
+    }
+
     /// Validates the API key and finalizes the config to a [`Config`] instance.
     pub fn build(self) -> Result<Config> {
         let api_key = self
@@ -172,7 +519,30 @@ impl ConfigBuilder {
             return Err(Error::InvalidApiKey("API key cannot be empty".to_string()));
         }
 
-        let test_mode = self.test_mode || api_key.starts_with("sk_test_");
+        let test_mode = self.test_mode || api_key.starts_with(Mode::Test.key_prefix());
+
+        if let Some(proxy) = &self.proxy {
+            Proxy::all(proxy.as_str())
+                .map_err(|e| Error::Config(format!("Invalid proxy URL: {e}")))?;
+        }
+
+        let mut root_certificates = Vec::new();
+        for pem in &self.root_certificates {
+            let certificates = Certificate::from_pem_bundle(pem)
+                .map_err(|e| Error::Config(format!("Invalid root certificate: {e}")))?;
+            if certificates.is_empty() {
+                return Err(Error::Config(
+                    "Root certificate PEM contains no certificates".to_string(),
+                ));
+            }
+            root_certificates.extend(certificates);
+        }
+
+        let identity = self
+            .identity
+            .as_ref()
+            .map(|(certificate, private_key)| parse_identity(certificate, private_key))
+            .transpose()?;
 
         Ok(Config {
             api_key,
@@ -186,10 +556,220 @@ impl ConfigBuilder {
                 .user_agent
                 .unwrap_or_else(|| format!("payrex-rust/{}", crate::VERSION)),
             test_mode,
+            proxy: self.proxy,
+            no_proxy: self.no_proxy,
+            root_certificates,
+            identity,
+            pool_idle_timeout: self.pool_idle_timeout,
+            pool_max_idle_per_host: self.pool_max_idle_per_host,
+            check_livemode: self.check_livemode.unwrap_or(cfg!(debug_assertions)),
+            execution_mode: self.execution_mode,
         })
     }
 }
 
+/// Settings shared by environment variables and configuration files.
+#[derive(Debug, Default, Deserialize)]
+struct Settings {
+    api_key: Option<String>,
+    api_base_url: Option<String>,
+    timeout: Option<u64>,
+    max_retries: Option<u32>,
+    retry_delay_ms: Option<u64>,
+    user_agent: Option<String>,
+    test_mode: Option<bool>,
+    proxy: Option<String>,
+    no_proxy: Option<String>,
+    #[serde(default)]
+    root_certificates: Vec<PathBuf>,
+    pool_idle_timeout: Option<u64>,
+    pool_max_idle_per_host: Option<usize>,
+    check_livemode: Option<bool>,
+    execution_mode: Option<ExecutionMode>,
+}
+
+impl Settings {
+    fn from_lookup(lookup: impl Fn(&str) -> Option<String>) -> Result<Self> {
+        let var = |key: &str| lookup(key).filter(|value| !value.is_empty());
+
+        Ok(Self {
+            api_key: var("PAYREX_API_KEY"),
+            api_base_url: var("PAYREX_API_BASE_URL"),
+            timeout: parse_var(&var, "PAYREX_TIMEOUT")?,
+            max_retries: parse_var(&var, "PAYREX_MAX_RETRIES")?,
+            retry_delay_ms: parse_var(&var, "PAYREX_RETRY_DELAY_MS")?,
+            user_agent: var("PAYREX_USER_AGENT"),
+            test_mode: var("PAYREX_TEST_MODE")
+                .map(|value| parse_bool("PAYREX_TEST_MODE", &value))
+                .transpose()?,
+            proxy: var("PAYREX_PROXY"),
+            no_proxy: var("PAYREX_NO_PROXY"),
+            root_certificates: var("PAYREX_ROOT_CERTIFICATES")
+                .map(|paths| std::env::split_paths(&paths).collect())
+                .unwrap_or_default(),
+            pool_idle_timeout: parse_var(&var, "PAYREX_POOL_IDLE_TIMEOUT")?,
+            pool_max_idle_per_host: parse_var(&var, "PAYREX_POOL_MAX_IDLE_PER_HOST")?,
+            check_livemode: var("PAYREX_CHECK_LIVEMODE")
+                .map(|value| parse_bool("PAYREX_CHECK_LIVEMODE", &value))
+                .transpose()?,
+            execution_mode: parse_var(&var, "PAYREX_EXECUTION_MODE")?,
+        })
+    }
+
+    /// Layers `other` on top of `self`, keeping the values of `self` that `other` leaves unset.
+    fn merge(self, other: Self) -> Self {
+        Self {
+            api_key: other.api_key.or(self.api_key),
+            api_base_url: other.api_base_url.or(self.api_base_url),
+            timeout: other.timeout.or(self.timeout),
+            max_retries: other.max_retries.or(self.max_retries),
+            retry_delay_ms: other.retry_delay_ms.or(self.retry_delay_ms),
+            user_agent: other.user_agent.or(self.user_agent),
+            test_mode: other.test_mode.or(self.test_mode),
+            proxy: other.proxy.or(self.proxy),
+            no_proxy: other.no_proxy.or(self.no_proxy),
+            root_certificates: if other.root_certificates.is_empty() {
+                self.root_certificates
+            } else {
+                other.root_certificates
+            },
+            pool_idle_timeout: other.pool_idle_timeout.or(self.pool_idle_timeout),
+            pool_max_idle_per_host: other.pool_max_idle_per_host.or(self.pool_max_idle_per_host),
+            check_livemode: other.check_livemode.or(self.check_livemode),
+            execution_mode: other.execution_mode.or(self.execution_mode),
+        }
+    }
+
+    fn apply(self, mut builder: ConfigBuilder) -> Result<ConfigBuilder> {
+        if let Some(api_key) = self.api_key {
+            builder = builder.api_key(api_key);
+        }
+        if let Some(url) = self.api_base_url {
+            builder = builder.api_base_url(url);
+        }
+        if let Some(seconds) = self.timeout {
+            builder = builder.timeout(Duration::from_secs(seconds));
+        }
+        if let Some(max_retries) = self.max_retries {
+            builder = builder.max_retries(max_retries);
+        }
+        if let Some(millis) = self.retry_delay_ms {
+            builder = builder.retry_delay(Duration::from_millis(millis));
+        }
+        if let Some(user_agent) = self.user_agent {
+            builder = builder.user_agent(user_agent);
+        }
+        if let Some(enabled) = self.test_mode {
+            builder = builder.test_mode(enabled);
+        }
+        if let Some(url) = self.proxy {
+            builder = builder.proxy(url);
+        }
+        if let Some(hosts) = self.no_proxy {
+            builder = builder.no_proxy(hosts);
+        }
+        for path in self.root_certificates {
+            let pem = std::fs::read(&path).map_err(|e| {
+                Error::Config(format!(
+                    "Failed to read root certificate {}: {e}",
+                    path.display()
+                ))
+            })?;
+            builder = builder.add_root_certificate(pem);
+        }
+        if let Some(seconds) = self.pool_idle_timeout {
+            builder = builder.pool_idle_timeout(Duration::from_secs(seconds));
+        }
+        if let Some(max) = self.pool_max_idle_per_host {
+            builder = builder.pool_max_idle_per_host(max);
+        }
+        if let Some(enabled) = self.check_livemode {
+            builder = builder.check_livemode(enabled);
+        }
+        if let Some(mode) = self.execution_mode {
+            builder = builder.execution_mode(mode);
+        }
+
+        Ok(builder)
+    }
+}
+
+/// Layout of a TOML configuration file: top-level settings plus named profiles.
+#[derive(Debug, Default, Deserialize)]
+struct ConfigFile {
+    #[serde(flatten)]
+    defaults: Settings,
+
+    #[serde(default)]
+    profiles: HashMap<String, Settings>,
+}
+
+impl ConfigFile {
+    fn parse(contents: &str) -> Result<Self> {
+        toml::from_str(contents).map_err(|e| Error::Config(format!("Invalid config file: {e}")))
+    }
+
+    fn settings(mut self, profile: Option<&str>) -> Result<Settings> {
+        let Some(name) = profile else {
+            return Ok(self.defaults);
+        };
+
+        let overrides = self
+            .profiles
+            .remove(name)
+            .ok_or_else(|| Error::Config(format!("Profile `{name}` not found in config file")))?;
+
+        Ok(self.defaults.merge(overrides))
+    }
+}
+
+fn parse_var<T>(var: &impl Fn(&str) -> Option<String>, key: &str) -> Result<Option<T>>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    var(key)
+        .map(|value| {
+            value
+                .trim()
+                .parse()
+                .map_err(|e| Error::Config(format!("Invalid value for {key}: {e}")))
+        })
+        .transpose()
+}
+
+/// Returns `true` if the [`FORBID_LIVE_ENV`] safety guard is set to a truthy value.
+pub(crate) fn live_mode_forbidden() -> bool {
+    std::env::var(FORBID_LIVE_ENV)
+        .ok()
+        .filter(|value| !value.is_empty())
+        .is_some_and(|value| parse_bool(FORBID_LIVE_ENV, &value).unwrap_or(true))
+}
+
+fn parse_bool(key: &str, value: &str) -> Result<bool> {
+    match value.trim().to_ascii_lowercase().as_str() {
+        "1" | "true" | "yes" => Ok(true),
+        "0" | "false" | "no" => Ok(false),
+        _ => Err(Error::Config(format!(
+            "Invalid value for {key}: expected a boolean, got `{value}`"
+        ))),
+    }
+}
+
+#[cfg(feature = "native-tls")]
+fn parse_identity(certificate: &[u8], private_key: &[u8]) -> Result<Identity> {
+    Identity::from_pkcs8_pem(certificate, private_key)
+        .map_err(|e| Error::Config(format!("Invalid client identity: {e}")))
+}
+
+#[cfg(not(feature = "native-tls"))]
+fn parse_identity(certificate: &[u8], private_key: &[u8]) -> Result<Identity> {
+    let mut pem = certificate.to_vec();
+    pem.push(b'\n');
+    pem.extend_from_slice(private_key);
+    Identity::from_pem(&pem).map_err(|e| Error::Config(format!("Invalid client identity: {e}")))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -259,4 +839,206 @@ mod tests {
             .unwrap();
         assert!(explicit_config.is_test_mode());
     }
+
+    #[test]
+    fn test_config_mode() {
+        let config = Config::new("sk_test_abc123").unwrap();
+        assert_eq!(config.mode(), Mode::Test);
+        assert!(config.ensure_mode(Mode::Test).is_ok());
+        assert!(config.ensure_mode(Mode::Live).is_err());
+
+        let config = Config::new("sk_live_abc123").unwrap();
+        assert_eq!(config.mode(), Mode::Live);
+        assert!(config.ensure_mode(Mode::Live).is_ok());
+        assert!(config.ensure_mode(Mode::Test).is_err());
+    }
+
+    #[test]
+    fn test_config_mode_prefix_mismatch() {
+        let config = Config::builder()
+            .api_key("sk_live_abc123")
+            .test_mode(true)
+            .build()
+            .unwrap();
+        assert!(matches!(
+            config.ensure_mode(Mode::Test),
+            Err(Error::InvalidApiKey(_))
+        ));
+
+        let config = Config::new("some_other_key").unwrap();
+        assert!(matches!(
+            config.ensure_mode(Mode::Live),
+            Err(Error::InvalidApiKey(_))
+        ));
+    }
+
+    fn lookup(vars: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+        let vars: HashMap<String, String> = vars
+            .iter()
+            .map(|(k, v)| ((*k).to_string(), (*v).to_string()))
+            .collect();
+        move |key| vars.get(key).cloned()
+    }
+
+    #[test]
+    fn test_config_from_lookup() {
+        let config = ConfigBuilder::from_lookup(lookup(&[
+            ("PAYREX_API_KEY", "sk_test_abc123"),
+            ("PAYREX_API_BASE_URL", "https://example.com"),
+            ("PAYREX_TIMEOUT", "60"),
+            ("PAYREX_MAX_RETRIES", "5"),
+            ("PAYREX_RETRY_DELAY_MS", "250"),
+            ("PAYREX_USER_AGENT", ""),
+        ]))
+        .unwrap()
+        .build()
+        .unwrap();
+
+        assert_eq!(config.api_key(), "sk_test_abc123");
+        assert_eq!(config.api_base_url(), "https://example.com");
+        assert_eq!(config.timeout(), Duration::from_secs(60));
+        assert_eq!(config.max_retries(), 5);
+        assert_eq!(config.retry_delay(), Duration::from_millis(250));
+        assert!(config.user_agent().starts_with("payrex-rust/"));
+        assert!(config.is_test_mode());
+    }
+
+    #[test]
+    fn test_config_from_lookup_missing_api_key() {
+        let result = ConfigBuilder::from_lookup(lookup(&[("PAYREX_TIMEOUT", "60")]))
+            .unwrap()
+            .build();
+
+        assert!(matches!(result, Err(Error::Config(_))));
+    }
+
+    #[test]
+    fn test_config_from_lookup_invalid_values() {
+        let result = ConfigBuilder::from_lookup(lookup(&[("PAYREX_TIMEOUT", "soon")]));
+        assert!(matches!(result, Err(Error::Config(_))));
+
+        let result = ConfigBuilder::from_lookup(lookup(&[("PAYREX_TEST_MODE", "maybe")]));
+        assert!(matches!(result, Err(Error::Config(_))));
+    }
+
+    #[test]
+    fn test_config_file_profiles() {
+        let file = ConfigFile::parse(
+            r#"
+            timeout = 45
+            max_retries = 2
+
+            [profiles.test]
+            api_key = "sk_test_abc123"
+
+            [profiles.live]
+            api_key = "sk_live_abc123"
+            max_retries = 5
+            "#,
+        )
+        .unwrap();
+
+        let live = file
+            .settings(Some("live"))
+            .unwrap()
+            .apply(ConfigBuilder::new())
+            .unwrap()
+            .build()
+            .unwrap();
+
+        assert_eq!(live.api_key(), "sk_live_abc123");
+        assert_eq!(live.timeout(), Duration::from_secs(45));
+        assert_eq!(live.max_retries(), 5);
+        assert!(!live.is_test_mode());
+    }
+
+    #[test]
+    fn test_config_file_missing_profile() {
+        let file = ConfigFile::parse("api_key = \"sk_test_abc123\"").unwrap();
+        assert!(matches!(file.settings(Some("live")), Err(Error::Config(_))));
+    }
+
+    #[test]
+    fn test_config_from_missing_file() {
+        let result = Config::from_file("does/not/exist.toml");
+        assert!(matches!(result, Err(Error::Config(_))));
+    }
+
+    #[test]
+    fn test_config_builder_network_options() {
+        let config = Config::builder()
+            .api_key("test_key")
+            .proxy("http://proxy.internal:3128")
+            .no_proxy("localhost,.internal")
+            .pool_idle_timeout(Duration::from_secs(90))
+            .pool_max_idle_per_host(8)
+            .build()
+            .unwrap();
+
+        assert_eq!(config.proxy(), Some("http://proxy.internal:3128"));
+        assert_eq!(config.no_proxy(), Some("localhost,.internal"));
+        assert_eq!(config.pool_idle_timeout(), Some(Duration::from_secs(90)));
+        assert_eq!(config.pool_max_idle_per_host(), Some(8));
+    }
+
+    #[test]
+    fn test_config_builder_check_livemode() {
+        let config = Config::builder().api_key("test_key").build().unwrap();
+        assert_eq!(config.check_livemode(), cfg!(debug_assertions));
+
+        let config = Config::builder()
+            .api_key("test_key")
+            .check_livemode(false)
+            .build()
+            .unwrap();
+        assert!(!config.check_livemode());
+    }
+
+    #[test]
+    fn test_config_execution_mode() {
+        let config = Config::new("sk_test_abc123").unwrap();
+        assert_eq!(config.execution_mode(), ExecutionMode::Normal);
+
+        let config = ConfigBuilder::from_lookup(lookup(&[
+            ("PAYREX_API_KEY", "sk_test_abc123"),
+            ("PAYREX_EXECUTION_MODE", "dry_run"),
+        ]))
+        .unwrap()
+        .build()
+        .unwrap();
+        assert_eq!(config.execution_mode(), ExecutionMode::DryRun);
+
+        let result = ConfigBuilder::from_lookup(lookup(&[("PAYREX_EXECUTION_MODE", "never")]));
+        assert!(matches!(result, Err(Error::Config(_))));
+    }
+
+    #[test]
+    fn test_config_builder_invalid_proxy() {
+        let result = Config::builder()
+            .api_key("test_key")
+            .proxy("not a url")
+            .build();
+
+        assert!(matches!(result, Err(Error::Config(_))));
+    }
+
+    #[test]
+    fn test_config_builder_invalid_root_certificate() {
+        let result = Config::builder()
+            .api_key("test_key")
+            .add_root_certificate("not a certificate")
+            .build();
+
+        assert!(matches!(result, Err(Error::Config(_))));
+    }
+
+    #[test]
+    fn test_config_builder_invalid_identity() {
+        let result = Config::builder()
+            .api_key("test_key")
+            .identity("not a certificate", "not a key")
+            .build();
+
+        assert!(matches!(result, Err(Error::Config(_))));
+    }
 }
@@ -3,6 +3,10 @@
 //! This module provides comprehensive error handling using the `thiserror` crate.
 //! All errors implement `std::error::Error` and can be easily converted and propagated.
 
+use crate::{
+    config::Mode,
+    types::{Currency, CustomerId, ParseAmountError},
+};
 use std::{fmt, str::FromStr};
 
 #[allow(missing_docs)]
@@ -78,6 +82,90 @@ pub enum Error {
     #[error("Idempotency error: {0}")]
     Idempotency(String),
 
+    /// A read-only client refused to send a request that is not a `GET`.
+    #[error("Read-only client refused to send {method} {path}")]
+    ReadOnly {
+        /// The HTTP method of the refused request.
+        method: String,
+
+        /// The path of the refused request.
+        path: String,
+    },
+
+    /// A dry-run client recorded a mutating request instead of sending it.
+    #[error("Dry run: {method} {path} was not sent")]
+    DryRun {
+        /// The HTTP method of the recorded request.
+        method: String,
+
+        /// The path of the recorded request.
+        path: String,
+    },
+
+    /// The `livemode` flag of a response contradicts the mode of the client. This usually means
+    /// the API key belongs to a different mode than the configuration expects.
+    #[error("Livemode mismatch: expected a {expected} mode response, got a {actual} mode response")]
+    LivemodeMismatch {
+        /// The mode of the client that sent the request.
+        expected: Mode,
+
+        /// The mode reported by the `livemode` flag of the response.
+        actual: Mode,
+    },
+
+    /// The requested capture amount exceeds what the payment intent can still capture.
+    #[error("Capture amount {requested} exceeds the capturable amount {capturable}")]
+    CaptureAmountExceeded {
+        /// The amount requested to be captured.
+        requested: u64,
+
+        /// The `amount_capturable` of the payment intent.
+        capturable: u64,
+    },
+
+    /// The requested refund amount exceeds what is left to refund on the payment.
+    #[error("Refund amount {requested} exceeds the refundable amount {refundable}")]
+    RefundAmountExceeded {
+        /// The amount requested to be refunded.
+        requested: u64,
+
+        /// The amount of the payment that has not been refunded yet.
+        refundable: u64,
+    },
+
+    /// Two amounts of different currencies were combined.
+    #[error("Currency mismatch: expected {expected}, got {actual}")]
+    CurrencyMismatch {
+        /// The currency of the left-hand amount.
+        expected: Currency,
+
+        /// The currency of the right-hand amount.
+        actual: Currency,
+    },
+
+    /// A human-formatted amount could not be parsed.
+    #[error("Invalid amount: {0}")]
+    ParseAmount(#[from] ParseAmountError),
+
+    /// An arithmetic operation on amounts overflowed.
+    #[error("Amount overflow")]
+    AmountOverflow,
+
+    /// An amount was converted from major units in a currency whose number of decimal places is
+    /// not known to this version of the SDK.
+    #[error("Unknown number of decimal places for currency {0}")]
+    UnknownCurrency(Currency),
+
+    /// More than one customer shares the e-mail address of a lookup that requires a single match.
+    #[error("Found {} customers with the e-mail address {email}", ids.len())]
+    DuplicateCustomer {
+        /// The e-mail address that was looked up.
+        email: String,
+
+        /// The IDs of every customer with the e-mail address.
+        ids: Vec<CustomerId>,
+    },
+
     /// Fallback error type. This is mostly for internal errors.
     #[error("Internal error: {0}")]
     Internal(String),
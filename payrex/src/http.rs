@@ -3,16 +3,42 @@
 //! This module provides a wrapper around `reqwest` with automatic retries,
 //! rate limiting, and proper error handling for the PayRex API.
 
-use crate::{Config, Error, ErrorKind, Result};
+use crate::{Config, Error, ErrorKind, ExecutionMode, Mode, Result, types::Deleted};
 use base64::{Engine as _, engine::general_purpose};
-use reqwest::{Client as ReqwestClient, RequestBuilder, Response, StatusCode, header};
+use reqwest::{
+    Client as ReqwestClient, Method, NoProxy, Proxy, RequestBuilder, Response, StatusCode, header,
+};
 use serde::{Serialize, de::DeserializeOwned};
-use std::time::Duration;
+use serde_json::Value;
+use std::{
+    sync::{Mutex, PoisonError},
+    time::Duration,
+};
+
+/// A mutating request recorded by a client in [`ExecutionMode::DryRun`] instead of being sent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DryRunCall {
+    /// The HTTP method of the request, e.g. `POST`.
+    pub method: String,
+
+    /// The path of the request relative to the API base URL.
+    pub path: String,
+
+    /// The form-encoded request body, empty for requests without one.
+    pub body: String,
+}
+
+/// Form-encodes a request body the way PayRex expects it, e.g. arrays as `key[]=value`.
+pub(crate) fn encode_form<B: Serialize>(body: &B) -> Result<String> {
+    serde_qs::to_string(body)
+        .map_err(|e| Error::Config(format!("Failed to serialize request body: {e}")))
+}
 
 /// HTTP client for making requests to the PayRex API.
 pub(crate) struct HttpClient {
     client: ReqwestClient,
     config: Config,
+    dry_run_calls: Mutex<Vec<DryRunCall>>,
 }
 
 impl HttpClient {
@@ -39,13 +65,45 @@ impl HttpClient {
             header::HeaderValue::from_static("application/x-www-form-urlencoded"),
         );
 
-        let client = ReqwestClient::builder()
+        let mut builder = ReqwestClient::builder()
             .default_headers(headers)
-            .timeout(config.timeout())
+            .timeout(config.timeout());
+
+        if let Some(url) = config.proxy() {
+            let no_proxy = config
+                .no_proxy()
+                .map_or_else(NoProxy::from_env, NoProxy::from_string);
+            let proxy = Proxy::all(url)
+                .map_err(|e| Error::Config(format!("Invalid proxy URL: {e}")))?
+                .no_proxy(no_proxy);
+            builder = builder.proxy(proxy);
+        }
+
+        for certificate in &config.root_certificates {
+            builder = builder.add_root_certificate(certificate.clone());
+        }
+
+        if let Some(identity) = &config.identity {
+            builder = builder.identity(identity.clone());
+        }
+
+        if let Some(timeout) = config.pool_idle_timeout() {
+            builder = builder.pool_idle_timeout(timeout);
+        }
+
+        if let Some(max) = config.pool_max_idle_per_host() {
+            builder = builder.pool_max_idle_per_host(max);
+        }
+
+        let client = builder
             .build()
             .map_err(|e| Error::Config(format!("Failed to build HTTP client: {e}")))?;
 
-        Ok(Self { client, config })
+        Ok(Self {
+            client,
+            config,
+            dry_run_calls: Mutex::new(Vec::new()),
+        })
     }
 
     pub async fn get<T: DeserializeOwned>(&self, path: &str) -> Result<T> {
@@ -59,16 +117,15 @@ impl HttpClient {
         body: &B,
     ) -> Result<T> {
         let url = self.build_url(path)?;
-        let form_data = serde_qs::to_string(body)
-            .map_err(|e| Error::Config(format!("Failed to serialize request body: {e}")))?;
+        let form_data = encode_form(body)?;
         self.execute_with_retry(|| self.client.get(&url).body(form_data.clone()))
             .await
     }
 
     pub async fn post<B: Serialize, T: DeserializeOwned>(&self, path: &str, body: &B) -> Result<T> {
         let url = self.build_url(path)?;
-        let form_data = serde_qs::to_string(body)
-            .map_err(|e| Error::Config(format!("Failed to serialize request body: {e}")))?;
+        let form_data = encode_form(body)?;
+        self.ensure_allowed(&Method::POST, path, &form_data)?;
         self.execute_with_retry(|| self.client.post(&url).body(form_data.clone()))
             .await
     }
@@ -76,8 +133,8 @@ impl HttpClient {
     #[allow(dead_code)]
     pub async fn put<B: Serialize, T: DeserializeOwned>(&self, path: &str, body: &B) -> Result<T> {
         let url = self.build_url(path)?;
-        let form_data = serde_qs::to_string(body)
-            .map_err(|e| Error::Config(format!("Failed to serialize request body: {e}")))?;
+        let form_data = encode_form(body)?;
+        self.ensure_allowed(&Method::PUT, path, &form_data)?;
         self.execute_with_retry(|| self.client.put(&url).body(form_data.clone()))
             .await
     }
@@ -88,15 +145,74 @@ impl HttpClient {
         body: &B,
     ) -> Result<T> {
         let url = self.build_url(path)?;
-        let form_data = serde_qs::to_string(body)
-            .map_err(|e| Error::Config(format!("Failed to serialize request body: {e}")))?;
+        let form_data = encode_form(body)?;
+        self.ensure_allowed(&Method::PATCH, path, &form_data)?;
         self.execute_with_retry(|| self.client.patch(&url).body(form_data.clone()))
             .await
     }
 
-    pub async fn delete<T: DeserializeOwned>(&self, path: &str) -> Result<T> {
+    /// Deletes a resource. An empty response body, such as a `204 No Content`, is reported as a
+    /// successful deletion of `id`.
+    pub async fn delete<Id: DeserializeOwned + Clone>(
+        &self,
+        path: &str,
+        id: &Id,
+    ) -> Result<Deleted<Id>> {
         let url = self.build_url(path)?;
-        self.execute_with_retry(|| self.client.delete(&url)).await
+        self.ensure_allowed(&Method::DELETE, path, "")?;
+        let deleted: Option<Deleted<Id>> =
+            self.execute_with_retry(|| self.client.delete(&url)).await?;
+        Ok(deleted.unwrap_or_else(|| Deleted::new(id.clone())))
+    }
+
+    /// Returns the mutating requests recorded so far in [`ExecutionMode::DryRun`].
+    pub fn dry_run_calls(&self) -> Vec<DryRunCall> {
+        self.dry_run_calls
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// Returns and clears the mutating requests recorded so far in [`ExecutionMode::DryRun`].
+    pub fn take_dry_run_calls(&self) -> Vec<DryRunCall> {
+        std::mem::take(
+            &mut *self
+                .dry_run_calls
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner),
+        )
+    }
+
+    /// Refuses or records a request according to the [`ExecutionMode`] of the client. `GET`
+    /// requests are always allowed.
+    fn ensure_allowed(&self, method: &Method, path: &str, body: &str) -> Result<()> {
+        if *method == Method::GET {
+            return Ok(());
+        }
+
+        match self.config.execution_mode() {
+            ExecutionMode::Normal => Ok(()),
+            ExecutionMode::ReadOnly => Err(Error::ReadOnly {
+                method: method.to_string(),
+                path: path.to_owned(),
+            }),
+            ExecutionMode::DryRun => {
+                log::info!(target: "payrex::dry_run", "{method} {path} {body}");
+                self.dry_run_calls
+                    .lock()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .push(DryRunCall {
+                        method: method.to_string(),
+                        path: path.to_owned(),
+                        body: body.to_owned(),
+                    });
+
+                Err(Error::DryRun {
+                    method: method.to_string(),
+                    path: path.to_owned(),
+                })
+            }
+        }
     }
 
     fn build_url(&self, path: &str) -> Result<String> {
@@ -169,7 +285,43 @@ impl HttpClient {
             });
         }
 
-        response.json().await.map_err(Error::Http)
+        let bytes = response.bytes().await.map_err(Error::Http)?;
+
+        if bytes.iter().all(u8::is_ascii_whitespace) {
+            return serde_json::from_value(Value::Null).map_err(Error::Json);
+        }
+
+        if self.config.check_livemode() {
+            let body: Value = serde_json::from_slice(&bytes)?;
+            self.ensure_livemode(&body)?;
+            return serde_json::from_value(body).map_err(Error::Json);
+        }
+
+        serde_json::from_slice(&bytes).map_err(Error::Json)
+    }
+
+    /// Rejects a response whose `livemode` flag, or that of any item in a list response,
+    /// contradicts the mode of the client.
+    fn ensure_livemode(&self, body: &Value) -> Result<()> {
+        let expected = self.config.mode();
+        let items = body
+            .get("data")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten();
+
+        let mismatch = std::iter::once(body)
+            .chain(items)
+            .filter_map(|value| value.get("livemode").and_then(Value::as_bool))
+            .find(|&livemode| livemode != (expected == Mode::Live));
+
+        match mismatch {
+            Some(livemode) => Err(Error::LivemodeMismatch {
+                expected,
+                actual: if livemode { Mode::Live } else { Mode::Test },
+            }),
+            None => Ok(()),
+        }
     }
 
     fn status_to_error_kind(status: StatusCode) -> ErrorKind {
@@ -205,6 +357,97 @@ mod tests {
         assert!(url.starts_with("https://"));
     }
 
+    #[test]
+    fn test_new_with_proxy_and_pool_options() {
+        let config = Config::builder()
+            .api_key("test_key")
+            .proxy("http://proxy.internal:3128")
+            .no_proxy("localhost")
+            .pool_idle_timeout(Duration::from_secs(30))
+            .pool_max_idle_per_host(4)
+            .build()
+            .unwrap();
+
+        assert!(HttpClient::new(config).is_ok());
+    }
+
+    #[test]
+    fn test_ensure_livemode() {
+        let config = Config::new("sk_test_abc123").unwrap();
+        let client = HttpClient::new(config).unwrap();
+
+        assert!(
+            client
+                .ensure_livemode(&serde_json::json!({ "livemode": false }))
+                .is_ok()
+        );
+        assert!(
+            client
+                .ensure_livemode(&serde_json::json!({ "data": [{ "livemode": false }] }))
+                .is_ok()
+        );
+        assert!(matches!(
+            client.ensure_livemode(&serde_json::json!({ "livemode": true })),
+            Err(Error::LivemodeMismatch {
+                expected: Mode::Test,
+                actual: Mode::Live,
+            })
+        ));
+        assert!(matches!(
+            client.ensure_livemode(&serde_json::json!({
+                "data": [{ "livemode": false }, { "livemode": true }]
+            })),
+            Err(Error::LivemodeMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_ensure_allowed_read_only() {
+        let config = Config::builder()
+            .api_key("sk_test_abc123")
+            .execution_mode(ExecutionMode::ReadOnly)
+            .build()
+            .unwrap();
+        let client = HttpClient::new(config).unwrap();
+
+        assert!(
+            client
+                .ensure_allowed(&Method::GET, "/customers", "")
+                .is_ok()
+        );
+        assert!(matches!(
+            client.ensure_allowed(&Method::POST, "/customers", "name=Juan"),
+            Err(Error::ReadOnly { .. })
+        ));
+        assert!(client.dry_run_calls().is_empty());
+    }
+
+    #[test]
+    fn test_ensure_allowed_dry_run() {
+        let config = Config::builder()
+            .api_key("sk_test_abc123")
+            .execution_mode(ExecutionMode::DryRun)
+            .build()
+            .unwrap();
+        let client = HttpClient::new(config).unwrap();
+
+        assert!(
+            client
+                .ensure_allowed(&Method::GET, "/customers", "")
+                .is_ok()
+        );
+        assert!(matches!(
+            client.ensure_allowed(&Method::DELETE, "/customers/cus_123", ""),
+            Err(Error::DryRun { .. })
+        ));
+
+        let calls = client.take_dry_run_calls();
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0].method, "DELETE");
+        assert_eq!(calls[0].path, "/customers/cus_123");
+        assert!(client.dry_run_calls().is_empty());
+    }
+
     #[test]
     fn test_calculate_retry_delay() {
         let config = Config::builder()
@@ -23,9 +23,10 @@ pub mod types;
 pub mod resources;
 
 // Re-exports
-pub use client::Client;
-pub use config::{Config, ConfigBuilder};
+pub use client::{Client, ClientSet};
+pub use config::{Config, ConfigBuilder, ExecutionMode, FORBID_LIVE_ENV, Mode};
 pub use error::{Error, ErrorKind, Result};
+pub use http::DryRunCall;
 
 /// Version of this crate
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
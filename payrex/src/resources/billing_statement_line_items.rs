@@ -10,7 +10,11 @@ use serde::{Deserialize, Serialize};
 use crate::{
     Result,
     http::HttpClient,
-    types::{BillingStatementId, BillingStatementLineItemId, Timestamp},
+    resources::billing_statements::BillingStatement,
+    types::{
+        BillingStatementId, BillingStatementLineItemId, Deleted, ExpandParams, Money, ObjectType,
+        Resource, Timestamp,
+    },
 };
 
 /// Billing Statement Lines API
@@ -39,6 +43,23 @@ impl BillingStatementLineItems {
             .await
     }
 
+    /// Lists the line items of a billing statement.
+    ///
+    /// PayRex does not document a separate line item listing endpoint, so the line items are read
+    /// from the billing statement itself with `line_items` expanded.
+    ///
+    /// Endpoint: `GET /billing_statements/:id`
+    ///
+    /// [API Reference](https://docs.payrexhq.com/docs/api/billing_statements/retrieve)
+    pub async fn list(&self, id: &BillingStatementId) -> Result<Vec<BillingStatementLineItem>> {
+        let params = ExpandParams::new().expand("line_items");
+        let statement: BillingStatement = self
+            .http
+            .get_with_params(&format!("/billing_statements/{}", id.as_str()), &params)
+            .await?;
+        Ok(statement.line_items.unwrap_or_default())
+    }
+
     /// Updates a billing statement line item resource.
     ///
     /// Endpoint: `PUT /billing_statement_line_items/:id`
@@ -62,16 +83,27 @@ impl BillingStatementLineItems {
     /// Endpoint: `DELETE /billing_statement_line_items/:id`
     ///
     /// [API Reference](https://docs.payrexhq.com/docs/api/billing_statement_line_items/delete)
-    pub async fn delete(&self, id: &BillingStatementLineItemId) -> Result<()> {
+    pub async fn delete(
+        &self,
+        id: &BillingStatementLineItemId,
+    ) -> Result<Deleted<BillingStatementLineItemId>> {
         self.http
-            .delete(&format!("/billing_statement_line_items/{}", id.as_str()))
+            .delete(
+                &format!("/billing_statement_line_items/{}", id.as_str()),
+                id,
+            )
             .await
     }
 }
 
 /// The billing statement line item is a line item of a billing statement that pertains to a
 /// business's products or services.
-#[payrex_attr(livemode, timestamp, description = "billing_statement_line_items")]
+#[payrex_attr(
+    resource,
+    livemode,
+    timestamp,
+    description = "billing_statement_line_items"
+)]
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct BillingStatementLineItem {
     /// Unique identifier for the resource. The prefix is `bstm_li_`.
@@ -91,6 +123,18 @@ pub struct BillingStatementLineItem {
     pub billing_statement_id: BillingStatementId,
 }
 
+impl Resource for BillingStatementLineItem {
+    type Id = BillingStatementLineItemId;
+
+    fn id(&self) -> &Self::Id {
+        &self.id
+    }
+
+    fn object_type() -> ObjectType {
+        ObjectType::BillingStatementLineItem
+    }
+}
+
 /// Query parameters when creating a billing statement line item.
 ///
 /// [Reference](https://docs.payrexhq.com/docs/api/billing_statement_line_items/create#parameters)
@@ -112,6 +156,28 @@ pub struct CreateBillingStatementLineItem {
     pub quantity: u64,
 }
 
+impl CreateBillingStatementLineItem {
+    /// Creates a new [`CreateBillingStatementLineItem`] charging `quantity` units of `unit_price`.
+    ///
+    /// The currency of `unit_price` is not sent: it is set by the billing statement.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidRequest`](crate::Error::InvalidRequest) if the unit price is
+    /// negative.
+    pub fn from_money(
+        billing_statement_id: BillingStatementId,
+        unit_price: Money,
+        quantity: u64,
+    ) -> Result<Self> {
+        Ok(Self::new(
+            billing_statement_id,
+            unit_price.to_minor()?,
+            quantity,
+        ))
+    }
+}
+
 /// Query parameters when updating a billing statement line item.
 ///
 /// [Reference](https://docs.payrexhq.com/docs/api/billing_statement_line_items/update#parameters)
@@ -133,10 +199,22 @@ pub struct UpdateBillingStatementLineItem {
     pub quantity: Option<u64>,
 }
 
+impl UpdateBillingStatementLineItem {
+    /// Sets the unit price for a line item in the billing statement from a [`Money`] amount.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidRequest`](crate::Error::InvalidRequest) if the unit price is
+    /// negative.
+    pub fn unit_price_money(self, unit_price: Money) -> Result<Self> {
+        Ok(self.unit_price(unit_price.to_minor()?))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::types::{BillingStatementId, BillingStatementLineItemId, Timestamp};
+    use crate::types::{BillingStatementId, BillingStatementLineItemId, Currency, Timestamp};
     use serde_json;
 
     #[test]
@@ -161,9 +239,43 @@ mod tests {
         assert_eq!(params.quantity, Some(5));
     }
 
+    #[test]
+    fn test_billing_statement_line_item_from_money() {
+        let unit_price = Money::new(1500, Currency::PHP);
+        let params = CreateBillingStatementLineItem::from_money(
+            BillingStatementId::new("bstm_1"),
+            unit_price.clone(),
+            3,
+        )
+        .unwrap();
+        assert_eq!(params.unit_price, 1500);
+        assert_eq!(params.quantity, 3);
+
+        let params = UpdateBillingStatementLineItem::new()
+            .unit_price_money(unit_price)
+            .unwrap();
+        assert_eq!(params.unit_price, Some(1500));
+
+        let negative = Money::new(-1, Currency::PHP);
+        assert!(
+            CreateBillingStatementLineItem::from_money(
+                BillingStatementId::new("bstm_1"),
+                negative.clone(),
+                1
+            )
+            .is_err()
+        );
+        assert!(
+            UpdateBillingStatementLineItem::new()
+                .unit_price_money(negative)
+                .is_err()
+        );
+    }
+
     #[test]
     fn test_billing_statement_line_item_serialization() {
         let item = BillingStatementLineItem {
+            resource: ObjectType::BillingStatementLineItem,
             id: BillingStatementLineItemId::new("bstm_li_1"),
             description: Some("Test item".to_string()),
             unit_price: 1200,
@@ -172,6 +284,7 @@ mod tests {
             livemode: false,
             created_at: Timestamp::from_unix(1_621_000_000),
             updated_at: Timestamp::from_unix(1_621_000_100),
+            extra: serde_json::Map::new(),
         };
         let json = serde_json::to_value(&item).unwrap();
         assert_eq!(json["id"], "bstm_li_1");
@@ -7,15 +7,17 @@ use crate::resources::payment_intents::OptionalPaymentIntent;
 use crate::{
     Result,
     http::HttpClient,
-    resources::customers::OptionalCustomer,
+    resources::{Listable, Retrievable, customers::OptionalCustomer},
     types::{
-        BillingStatementId, Currency, CustomerId, List, ListParams, Metadata, PaymentMethod,
-        Timestamp,
+        BillingStatementId, Currency, CustomerId, Deleted, ExpandParams, Expandable, List,
+        ListParams, ListStream, Metadata, ObjectType, PaymentIntentId, PaymentMethod, RangeQuery,
+        Resource, Timestamp,
+        pagination::{Paginate, paginate},
     },
 };
 use payrex_derive::{Payrex, payrex_attr};
 use serde::{Deserialize, Serialize};
-use std::sync::Arc;
+use std::{future::Future, sync::Arc};
 
 #[derive(Clone)]
 /// Billing statements are one-time payment links that contain customer information, the due date,
@@ -50,6 +52,22 @@ impl BillingStatements {
             .await
     }
 
+    /// Retrieves a billing statement resource with related resources such as `customer` and
+    /// `payment_intent` expanded inline.
+    ///
+    /// Endpoint: `GET /billing_statements/:id`
+    ///
+    /// [API Reference](https://docs.payrexhq.com/docs/api/billing_statements/retrieve)
+    pub async fn retrieve_with_params(
+        &self,
+        id: &BillingStatementId,
+        params: ExpandParams,
+    ) -> Result<BillingStatement> {
+        self.http
+            .get_with_params(&format!("/billing_statements/{}", id.as_str()), &params)
+            .await
+    }
+
     /// Updates a billing statement resource.
     ///
     /// Endpoint: `PUT /billing_statements/:id`
@@ -70,9 +88,9 @@ impl BillingStatements {
     /// Endpoint: `DELETE /billing_statements/:id`
     ///
     /// [API Reference](https://docs.payrexhq.com/docs/api/billing_statements/delete)
-    pub async fn delete(&self, id: &BillingStatementId) -> Result<()> {
+    pub async fn delete(&self, id: &BillingStatementId) -> Result<Deleted<BillingStatementId>> {
         self.http
-            .delete(&format!("/billing_statements/{}", id.as_str()))
+            .delete(&format!("/billing_statements/{}", id.as_str()), id)
             .await
     }
 
@@ -81,12 +99,29 @@ impl BillingStatements {
     /// Endpoint: `GET /billing_statements`
     ///
     /// [API Reference](https://docs.payrexhq.com/docs/api/billing_statements/list)
-    pub async fn list(&self, params: Option<ListParams>) -> Result<List<BillingStatement>> {
+    pub async fn list(
+        &self,
+        params: Option<BillingStatementListParams>,
+    ) -> Result<List<BillingStatement>> {
         self.http
             .get_with_params("/billing_statements", &params)
             .await
     }
 
+    /// Lists every billing statement resource, fetching the next page lazily as the stream is
+    /// consumed.
+    ///
+    /// Endpoint: `GET /billing_statements`
+    ///
+    /// [API Reference](https://docs.payrexhq.com/docs/api/billing_statements/list)
+    pub fn list_auto(&self, params: BillingStatementListParams) -> ListStream<BillingStatement> {
+        let http = Arc::clone(&self.http);
+        paginate(params, move |params: BillingStatementListParams| {
+            let http = Arc::clone(&http);
+            async move { http.get_with_params("/billing_statements", &params).await }
+        })
+    }
+
     /// Finalizes a billing statement resource.
     ///
     /// Endpoint: `POST /billing_statements/:id/finalize`
@@ -138,10 +173,29 @@ impl BillingStatements {
     }
 }
 
+impl Retrievable for BillingStatements {
+    type Id = BillingStatementId;
+    type Item = BillingStatement;
+
+    fn retrieve(&self, id: &Self::Id) -> impl Future<Output = Result<Self::Item>> + Send {
+        Self::retrieve(self, id)
+    }
+}
+
+impl Listable for BillingStatements {
+    type Item = BillingStatement;
+    type Params = BillingStatementListParams;
+
+    fn list(&self, params: Self::Params) -> impl Future<Output = Result<List<Self::Item>>> + Send {
+        Self::list(self, Some(params))
+    }
+}
+
 /// Billing Statement Resource.
 ///
 /// [Learn more about it here](https://docs.payrexhq.com/docs/api/billing_statements)
 #[payrex_attr(
+    resource,
     timestamp,
     livemode,
     metadata,
@@ -198,8 +252,13 @@ pub struct BillingStatement {
 
     /// The [PaymentIntent](https://docs.payrexhq.com/docs/api/payment_intents) resource created
     /// for the [`BillingStatement`].
+    ///
+    /// By default PayRex returns a partial object with fields such as `id`, `status` and
+    /// `client_secret`, decoded as an [`Expandable::Object`] whose missing fields are `None`.
+    /// Expanding `payment_intent` fills in the remaining fields. A bare ID decodes as an
+    /// [`Expandable::Id`].
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub payment_intent: Option<OptionalPaymentIntent>,
+    pub payment_intent: Option<Expandable<PaymentIntentId, OptionalPaymentIntent>>,
 
     /// The setup for future usage of this billing statement.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -217,8 +276,22 @@ pub struct BillingStatement {
     pub payment_settings: PaymentSettings,
 
     /// A customer resource that is associated with the billing statement (optional).
+    ///
+    /// Only the ID, name and e-mail are included unless `customer` is expanded.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub customer: Option<OptionalCustomer>,
+    pub customer: Option<Expandable<CustomerId, OptionalCustomer>>,
+}
+
+impl Resource for BillingStatement {
+    type Id = BillingStatementId;
+
+    fn id(&self) -> &Self::Id {
+        &self.id
+    }
+
+    fn object_type() -> ObjectType {
+        ObjectType::BillingStatement
+    }
 }
 
 /// Payment Settings for a billing statement.
@@ -305,6 +378,54 @@ pub struct UpdateBillingStatement {
     pub due_at: Option<Timestamp>,
 }
 
+/// Query parameters when listing billing statements.
+///
+/// [Reference](https://docs.payrexhq.com/docs/api/billing_statements/list#parameters)
+#[payrex_attr(metadata)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, Payrex)]
+pub struct BillingStatementListParams {
+    /// Baseline pagination fields such as `limit`, `before`, and `after`.
+    #[serde(flatten)]
+    pub list_params: ListParams,
+
+    /// Filters billing statements issued to a customer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[payrex(description = "Sets the customer ID when listing billing statements.")]
+    pub customer_id: Option<CustomerId>,
+
+    /// Filters billing statements by their latest status.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[payrex(description = "Sets the status when listing billing statements.")]
+    pub status: Option<BillingStatementStatus>,
+
+    /// Filters billing statements by the time they were created, measured in seconds since the
+    /// Unix epoch.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[payrex(description = "Sets the creation time range when listing billing statements.")]
+    pub created_at: Option<RangeQuery<Timestamp>>,
+
+    /// Filters billing statements by the time they are expected to be paid, measured in seconds
+    /// since the Unix epoch.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[payrex(description = "Sets the due date range when listing billing statements.")]
+    pub due_at: Option<RangeQuery<Timestamp>>,
+}
+
+impl BillingStatementListParams {
+    /// Includes the line items of each billing statement in the list.
+    #[must_use]
+    pub fn expand_line_items(mut self) -> Self {
+        self.list_params = self.list_params.expand("line_items");
+        self
+    }
+}
+
+impl Paginate for BillingStatementListParams {
+    fn list_params_mut(&mut self) -> &mut ListParams {
+        &mut self.list_params
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -408,6 +529,7 @@ mod tests {
         };
 
         let item = BillingStatementLineItem {
+            resource: ObjectType::BillingStatementLineItem,
             id: BillingStatementLineItemId::new("bstm_li_1"),
             description: Some("Test item".to_string()),
             unit_price: 1500,
@@ -416,8 +538,10 @@ mod tests {
             livemode: false,
             created_at: Timestamp::from_unix(1_620_003_000),
             updated_at: Timestamp::from_unix(1_620_003_000),
+            extra: serde_json::Map::new(),
         };
         let stmt = BillingStatement {
+            resource: ObjectType::BillingStatement,
             id: BillingStatementId::new("bstm_123"),
             amount: 2000,
             billing_details_collection: Some("mandatory".to_string()),
@@ -440,6 +564,7 @@ mod tests {
             customer: None,
             created_at: Timestamp::from_unix(1_620_000_000),
             updated_at: Timestamp::from_unix(1_620_001_000),
+            extra: serde_json::Map::new(),
         };
 
         let json = serde_json::to_value(&stmt).unwrap();
@@ -474,4 +599,30 @@ mod tests {
         assert_eq!(json["created_at"], 1_620_000_000);
         assert_eq!(json["updated_at"], 1_620_001_000);
     }
+
+    #[test]
+    fn test_billing_statement_list_params_ranges() {
+        let params = BillingStatementListParams::new()
+            .created_at(RangeQuery::new().gte(Timestamp::from_unix(1_700_000_000)))
+            .due_at(RangeQuery::new().lte(Timestamp::from_unix(1_700_086_400)));
+        let encoded = serde_qs::to_string(&params).unwrap();
+
+        assert!(encoded.contains("created_at[gte]=1700000000"));
+        assert!(encoded.contains("due_at[lte]=1700086400"));
+    }
+
+    #[test]
+    fn test_billing_statement_list_params_filters() {
+        let params = BillingStatementListParams::new()
+            .customer_id(CustomerId::new("cus_123"))
+            .status(BillingStatementStatus::Open)
+            .metadata(Metadata::with_pair("region", "ncr"))
+            .expand_line_items();
+        let encoded = crate::http::encode_form(&params).unwrap();
+
+        assert!(encoded.contains("customer_id=cus_123"));
+        assert!(encoded.contains("status=open"));
+        assert!(encoded.contains("metadata[region]=ncr"));
+        assert!(encoded.contains("expand[]=line_items"));
+    }
 }
@@ -5,15 +5,17 @@
 use crate::{
     Result,
     http::HttpClient,
-    resources::payment_intents::PaymentIntent,
+    resources::{Listable, Retrievable, payment_intents::PaymentIntent},
     types::{
-        CheckoutSessionId, CheckoutSessionLineItemId, Currency, Metadata, PaymentMethod,
-        PaymentMethodOptions, Timestamp,
+        CheckoutSessionId, CheckoutSessionLineItemId, Currency, List, ListParams, ListStream,
+        Metadata, Money, ObjectType, PaymentMethod, PaymentMethodOptions, RangeQuery, Resource,
+        Timestamp,
+        pagination::{Paginate, paginate},
     },
 };
 use payrex_derive::{Payrex, payrex_attr};
 use serde::{Deserialize, Serialize};
-use std::sync::Arc;
+use std::{future::Future, sync::Arc};
 
 /// Checkout Sessions API
 #[derive(Clone)]
@@ -49,6 +51,34 @@ impl CheckoutSessions {
             .await
     }
 
+    /// List CheckoutSession resources.
+    ///
+    /// Endpoint: `GET /checkout_sessions`
+    ///
+    /// [API Reference](https://docs.payrexhq.com/docs/api/checkout_sessions/list)
+    pub async fn list(
+        &self,
+        params: Option<CheckoutSessionListParams>,
+    ) -> Result<List<CheckoutSession>> {
+        self.http
+            .get_with_params("/checkout_sessions", &params)
+            .await
+    }
+
+    /// Lists every CheckoutSession resource, fetching the next page lazily as the stream is
+    /// consumed.
+    ///
+    /// Endpoint: `GET /checkout_sessions`
+    ///
+    /// [API Reference](https://docs.payrexhq.com/docs/api/checkout_sessions/list)
+    pub fn list_auto(&self, params: CheckoutSessionListParams) -> ListStream<CheckoutSession> {
+        let http = Arc::clone(&self.http);
+        paginate(params, move |params: CheckoutSessionListParams| {
+            let http = Arc::clone(&http);
+            async move { http.get_with_params("/checkout_sessions", &params).await }
+        })
+    }
+
     /// Expire a CheckoutSession resource.
     ///
     /// Endpoint: `POST /checkout_sessions/:id/expire`
@@ -61,10 +91,29 @@ impl CheckoutSessions {
     }
 }
 
+impl Retrievable for CheckoutSessions {
+    type Id = CheckoutSessionId;
+    type Item = CheckoutSession;
+
+    fn retrieve(&self, id: &Self::Id) -> impl Future<Output = Result<Self::Item>> + Send {
+        Self::retrieve(self, id)
+    }
+}
+
+impl Listable for CheckoutSessions {
+    type Item = CheckoutSession;
+    type Params = CheckoutSessionListParams;
+
+    fn list(&self, params: Self::Params) -> impl Future<Output = Result<List<Self::Item>>> + Send {
+        Self::list(self, Some(params))
+    }
+}
+
 /// A Checkout Session resource represents a one-time use PayRex-hosted checkout page and will
 /// expire at a certain period. To learn more about PayRex Checkout, you can refer to this
 /// [guide](https://docs.payrexhq.com/docs/guide/developer_handbook/payments/integrations/checkout).
 #[payrex_attr(
+    resource,
     livemode,
     timestamp,
     metadata,
@@ -145,6 +194,18 @@ pub struct CheckoutSession {
     pub expires_at: Option<Timestamp>,
 }
 
+impl Resource for CheckoutSession {
+    type Id = CheckoutSessionId;
+
+    fn id(&self) -> &Self::Id {
+        &self.id
+    }
+
+    fn object_type() -> ObjectType {
+        ObjectType::CheckoutSession
+    }
+}
+
 /// The latest status of a CheckoutSession.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -159,6 +220,9 @@ pub enum CheckoutSessionStatus {
 }
 
 /// List of items to pay during a checkout session.
+///
+/// Unlike other PayRex objects, a line item is not a [`Resource`]: it doubles as a create
+/// parameter, so it has no `resource` field and its ID is only set on responses.
 #[payrex_attr(amount = false, description = "checkout_session")]
 #[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize, Payrex)]
 pub struct CheckoutSessionLineItem {
@@ -182,6 +246,56 @@ pub struct CheckoutSessionLineItem {
     pub image: Option<String>,
 }
 
+impl CheckoutSessionLineItem {
+    /// Creates a new [`CheckoutSessionLineItem`] charging `quantity` units of `unit_price`.
+    ///
+    /// The currency of `unit_price` is not sent: it is set by the checkout session.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidRequest`](crate::Error::InvalidRequest) if the unit price is
+    /// negative.
+    pub fn from_money(name: impl Into<String>, quantity: u64, unit_price: Money) -> Result<Self> {
+        Ok(Self::new(name, quantity, unit_price.to_minor()?))
+    }
+}
+
+/// Query parameters when listing checkout sessions.
+///
+/// [Reference](https://docs.payrexhq.com/docs/api/checkout_sessions/list#parameters)
+#[derive(Debug, Default, Clone, Serialize, Deserialize, Payrex)]
+pub struct CheckoutSessionListParams {
+    /// Baseline pagination fields such as `limit`, `before`, and `after`.
+    #[serde(flatten)]
+    pub list_params: ListParams,
+
+    /// Filters checkout sessions by their latest status.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[payrex(description = "Sets the status in query params when listing checkout sessions.")]
+    pub status: Option<CheckoutSessionStatus>,
+
+    /// Filters checkout sessions by the reference set when they were created.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[payrex(
+        description = "Sets the customer reference ID in query params when listing checkout sessions."
+    )]
+    pub customer_reference_id: Option<String>,
+
+    /// Filters checkout sessions by the time they were created, measured in seconds since the Unix
+    /// epoch.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[payrex(
+        description = "Sets the creation time range in query params when listing checkout sessions."
+    )]
+    pub created_at: Option<RangeQuery<Timestamp>>,
+}
+
+impl Paginate for CheckoutSessionListParams {
+    fn list_params_mut(&mut self) -> &mut ListParams {
+        &mut self.list_params
+    }
+}
+
 /// Query parameters when creating a checkout session.
 ///
 /// [Reference](https://docs.payrexhq.com/docs/api/checkout_sessions/create#parameters)
@@ -282,6 +396,19 @@ mod tests {
         assert_eq!(item.image.as_deref(), Some("img_url"));
     }
 
+    #[test]
+    fn test_checkout_session_line_item_from_money() {
+        let item =
+            CheckoutSessionLineItem::from_money("Test item", 2, Money::new(1500, Currency::PHP))
+                .unwrap();
+        assert_eq!(item.amount, 1500);
+        assert_eq!(item.quantity, 2);
+        assert!(
+            CheckoutSessionLineItem::from_money("Test item", 2, Money::new(-1, Currency::PHP))
+                .is_err()
+        );
+    }
+
     #[test]
     fn test_checkout_session_line_item_serialization() {
         let mut item = CheckoutSessionLineItem::new("Test item", 2, 1500)
@@ -378,6 +505,7 @@ mod tests {
         };
 
         let session = CheckoutSession {
+            resource: ObjectType::CheckoutSession,
             id: CheckoutSessionId::new("cs_1"),
             amount: Some(1000),
             customer_reference_id: Some("cust".to_string()),
@@ -400,6 +528,7 @@ mod tests {
             expires_at: Some(Timestamp::from_unix(123_456)),
             created_at: Timestamp::from_unix(654_321),
             updated_at: Timestamp::from_unix(654_322),
+            extra: serde_json::Map::new(),
         };
 
         let json = serde_json::to_value(&session).unwrap();
@@ -436,4 +565,17 @@ mod tests {
         assert_eq!(json["created_at"], 654_321);
         assert_eq!(json["updated_at"], 654_322);
     }
+
+    #[test]
+    fn test_checkout_session_list_params_encoding() {
+        let params = CheckoutSessionListParams::new()
+            .status(CheckoutSessionStatus::Active)
+            .customer_reference_id("order_123")
+            .created_at(RangeQuery::new().lt(Timestamp::from_unix(1_700_000_000)));
+        let encoded = serde_qs::to_string(&params).unwrap();
+
+        assert!(encoded.contains("status=active"));
+        assert!(encoded.contains("customer_reference_id=order_123"));
+        assert!(encoded.contains("created_at[lt]=1700000000"));
+    }
 }
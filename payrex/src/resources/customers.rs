@@ -4,13 +4,19 @@
 //! multiple payments and billing information.
 
 use crate::{
-    Result,
+    Error, Result,
     http::HttpClient,
-    types::{Currency, CustomerId, List, ListParams, Metadata, Timestamp},
+    resources::{Listable, Retrievable},
+    types::{
+        Currency, CustomerId, Deleted, List, ListParams, ListStream, Metadata, ObjectType,
+        RangeQuery, Resource, Timestamp,
+        pagination::{Paginate, paginate},
+    },
 };
+use futures::TryStreamExt;
 use payrex_derive::{Payrex, payrex_attr};
 use serde::{Deserialize, Serialize};
-use std::sync::Arc;
+use std::{future::Future, sync::Arc};
 
 /// Customers API
 #[derive(Clone)]
@@ -61,9 +67,9 @@ impl Customers {
     /// Endpoint: `DELETE /customers/:id`
     ///
     /// [API Reference](https://docs.payrexhq.com/docs/api/customers/delete)
-    pub async fn delete(&self, id: &CustomerId) -> Result<()> {
+    pub async fn delete(&self, id: &CustomerId) -> Result<Deleted<CustomerId>> {
         self.http
-            .delete(&format!("/customers/{}", id.as_str()))
+            .delete(&format!("/customers/{}", id.as_str()), id)
             .await
     }
 
@@ -75,11 +81,116 @@ impl Customers {
     pub async fn list(&self, params: Option<CustomerListParams>) -> Result<List<Customer>> {
         self.http.get_with_params("/customers", &params).await
     }
+
+    /// Lists every customer resource, fetching the next page lazily as the stream is consumed.
+    ///
+    /// Endpoint: `GET /customers`
+    ///
+    /// [API Reference](https://docs.payrexhq.com/docs/api/customers/list)
+    pub fn list_auto(&self, params: CustomerListParams) -> ListStream<Customer> {
+        let http = Arc::clone(&self.http);
+        paginate(params, move |params: CustomerListParams| {
+            let http = Arc::clone(&http);
+            async move { http.get_with_params("/customers", &params).await }
+        })
+    }
+
+    /// Finds the customer with the e-mail address, comparing addresses case-insensitively.
+    /// Duplicate matches are resolved according to `on_duplicate`.
+    ///
+    /// Endpoint: `GET /customers`
+    ///
+    /// [API Reference](https://docs.payrexhq.com/docs/api/customers/list)
+    pub async fn find_by_email(
+        &self,
+        email: &str,
+        on_duplicate: DuplicateStrategy,
+    ) -> Result<Option<Customer>> {
+        let params = CustomerListParams::new().email(email);
+        let mut matches: Vec<Customer> = self
+            .list_auto(params)
+            .try_filter(|customer| {
+                let matched = customer
+                    .email
+                    .as_deref()
+                    .is_some_and(|found| found.eq_ignore_ascii_case(email));
+                async move { matched }
+            })
+            .try_collect()
+            .await?;
+
+        if matches.len() > 1 && on_duplicate == DuplicateStrategy::Error {
+            return Err(Error::DuplicateCustomer {
+                email: email.to_string(),
+                ids: matches.into_iter().map(|customer| customer.id).collect(),
+            });
+        }
+
+        matches.sort_by(|a, b| (a.created_at, a.id.as_str()).cmp(&(b.created_at, b.id.as_str())));
+        Ok(matches.pop())
+    }
+
+    /// Creates a customer, or updates the customer that already has the e-mail address of
+    /// `params`.
+    ///
+    /// When a customer is found, its name and currency are replaced with those of `params`. Its
+    /// billing statement settings and metadata are replaced only when `params` sets them, and kept
+    /// otherwise. With [`UpsertOptions::merge_metadata`], the metadata keys of `params` are added
+    /// to the existing metadata instead of replacing it.
+    ///
+    /// Endpoints: `GET /customers`, then `POST /customers` or `PUT /customers/:id`
+    pub async fn upsert(&self, params: CreateCustomer, options: UpsertOptions) -> Result<Customer> {
+        let Some(existing) = self
+            .find_by_email(&params.email, options.on_duplicate)
+            .await?
+        else {
+            return self.create(params).await;
+        };
+
+        let metadata = match (params.metadata, existing.metadata) {
+            (Some(new), Some(mut current)) if options.merge_metadata => {
+                for (key, value) in &new {
+                    current.insert(key.as_str(), value.as_str());
+                }
+                Some(current)
+            }
+            (new, _) => new,
+        };
+
+        let update = UpdateCustomer {
+            billing_statement_prefix: params.billing_statement_prefix,
+            next_billing_statement_sequence_number: params.next_billing_statement_sequence_number,
+            email: None,
+            name: Some(params.name),
+            currency: Some(params.currency),
+            metadata,
+        };
+
+        self.update(&existing.id, update).await
+    }
+}
+
+impl Retrievable for Customers {
+    type Id = CustomerId;
+    type Item = Customer;
+
+    fn retrieve(&self, id: &Self::Id) -> impl Future<Output = Result<Self::Item>> + Send {
+        Self::retrieve(self, id)
+    }
+}
+
+impl Listable for Customers {
+    type Item = Customer;
+    type Params = CustomerListParams;
+
+    fn list(&self, params: Self::Params) -> impl Future<Output = Result<List<Self::Item>>> + Send {
+        Self::list(self, Some(params))
+    }
 }
 
 /// A Customer resource represents the customer of your business. A customer could be a person or a
 /// company. Use this resource to track payments that belong to the same customer.
-#[payrex_attr(livemode, metadata, timestamp, optional, currency = true)]
+#[payrex_attr(resource, livemode, metadata, timestamp, optional, currency = true)]
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Customer {
     /// Unique identifier for the resource. The prefix is `cus_`.
@@ -106,6 +217,18 @@ pub struct Customer {
     pub next_billing_statement_sequence_number: Option<String>,
 }
 
+impl Resource for Customer {
+    type Id = CustomerId;
+
+    fn id(&self) -> &Self::Id {
+        &self.id
+    }
+
+    fn object_type() -> ObjectType {
+        ObjectType::Customer
+    }
+}
+
 /// Query parameters when creating a customer.
 ///
 /// [Reference](https://docs.payrexhq.com/docs/api/customers/create#parameters)
@@ -193,6 +316,62 @@ pub struct CustomerListParams {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[payrex(description = "Sets the name in query params when listing customers.")]
     pub name: Option<String>,
+
+    /// Filters customers by the time they were created, measured in seconds since the Unix
+    /// epoch.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[payrex(description = "Sets the creation time range in query params when listing customers.")]
+    pub created_at: Option<RangeQuery<Timestamp>>,
+}
+
+/// How [`Customers::find_by_email`] resolves several customers sharing an e-mail address.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum DuplicateStrategy {
+    /// Fails with [`Error::DuplicateCustomer`].
+    #[default]
+    Error,
+
+    /// Picks the most recently created customer.
+    NewestWins,
+}
+
+/// Options of [`Customers::upsert`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct UpsertOptions {
+    /// How several customers sharing the e-mail address are resolved.
+    pub on_duplicate: DuplicateStrategy,
+
+    /// Whether the metadata of an existing customer is merged with the new metadata instead of
+    /// being replaced.
+    pub merge_metadata: bool,
+}
+
+impl UpsertOptions {
+    /// Creates [`UpsertOptions`] that fail on duplicates and replace metadata.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets how several customers sharing the e-mail address are resolved.
+    #[must_use]
+    pub const fn on_duplicate(mut self, on_duplicate: DuplicateStrategy) -> Self {
+        self.on_duplicate = on_duplicate;
+        self
+    }
+
+    /// Sets whether the metadata of an existing customer is merged with the new metadata.
+    #[must_use]
+    pub const fn merge_metadata(mut self, merge_metadata: bool) -> Self {
+        self.merge_metadata = merge_metadata;
+        self
+    }
+}
+
+impl Paginate for CustomerListParams {
+    fn list_params_mut(&mut self) -> &mut ListParams {
+        &mut self.list_params
+    }
 }
 
 #[cfg(test)]
@@ -274,6 +453,7 @@ mod tests {
         let mut metadata = Metadata::new();
         metadata.insert("order_id", "12345");
         let customer = Customer {
+            resource: ObjectType::Customer,
             id: CustomerId::new("cus_123456"),
             billing_statement_prefix: Some("PREF".to_string()),
             currency: Some(Currency::PHP),
@@ -284,6 +464,7 @@ mod tests {
             next_billing_statement_sequence_number: Some("004".to_string()),
             created_at: Timestamp::from_unix(1_609_459_200),
             updated_at: Timestamp::from_unix(1_609_459_300),
+            extra: serde_json::Map::new(),
         };
         let json = serde_json::to_value(&customer).unwrap();
         assert_eq!(json["id"], "cus_123456");
@@ -298,6 +479,29 @@ mod tests {
         assert_eq!(json["updated_at"], 1_609_459_300);
     }
 
+    #[test]
+    fn test_customer_preserves_unknown_fields() {
+        let json = serde_json::json!({
+            "id": "cus_123456",
+            "resource": "customer",
+            "currency": "PHP",
+            "livemode": false,
+            "created_at": 1_609_459_200,
+            "updated_at": 1_609_459_300,
+            "future_field": { "enabled": true }
+        });
+
+        let customer: Customer = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(customer.extra.len(), 1);
+        assert_eq!(customer.extra["future_field"], json["future_field"]);
+        assert_eq!(serde_json::to_value(&customer).unwrap(), json);
+
+        let optional: OptionalCustomer = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(optional.id, Some(CustomerId::new("cus_123456")));
+        assert_eq!(optional.extra["future_field"], json["future_field"]);
+        assert_eq!(serde_json::to_value(&optional).unwrap(), json);
+    }
+
     #[test]
     fn test_customer_list_params_serialization() {
         let json_in = r#"
@@ -316,4 +520,17 @@ mod tests {
         assert_eq!(json["name"], "User Name");
         assert_eq!(json["metadata"]["foo"], "bar");
     }
+
+    #[test]
+    fn test_customer_list_params_created_at_encoding() {
+        let range = RangeQuery::new()
+            .gte(Timestamp::from_unix(1_700_000_000))
+            .lt(Timestamp::from_unix(1_700_086_400));
+        let params = CustomerListParams::new().created_at(range);
+        let encoded = serde_qs::to_string(&params).unwrap();
+
+        assert!(encoded.contains("created_at[gte]=1700000000"));
+        assert!(encoded.contains("created_at[lt]=1700086400"));
+        assert!(!encoded.contains("created_at[gt]="));
+    }
 }
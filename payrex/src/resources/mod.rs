@@ -11,6 +11,7 @@ pub mod payment_intents;
 pub mod payments;
 pub mod payouts;
 pub mod refunds;
+pub mod traits;
 pub mod webhooks;
 
 // Re-export resource types
@@ -22,4 +23,32 @@ pub use payment_intents::PaymentIntents;
 pub use payments::Payments;
 pub use payouts::Payouts;
 pub use refunds::Refunds;
+pub use traits::{Listable, Retrievable};
 pub use webhooks::Webhooks;
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        payment_intents::PaymentIntentStatus, payments::PaymentStatus, payouts::PayoutStatus,
+        refunds::RefundStatus, webhooks::WebhookStatus,
+    };
+
+    macro_rules! assert_unknown_round_trip {
+        ($($status:ty),* $(,)?) => {$(
+            let status: $status = serde_json::from_str("\"future_status\"").unwrap();
+            assert_eq!(status, <$status>::Unknown("future_status".to_string()));
+            assert_eq!(serde_json::to_string(&status).unwrap(), "\"future_status\"");
+        )*};
+    }
+
+    #[test]
+    fn test_status_unknown_round_trip() {
+        assert_unknown_round_trip!(
+            PaymentIntentStatus,
+            PaymentStatus,
+            PayoutStatus,
+            RefundStatus,
+            WebhookStatus,
+        );
+    }
+}
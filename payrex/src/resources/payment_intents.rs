@@ -4,16 +4,19 @@
 //! They track the lifecycle of a payment from creation through completion.
 
 use crate::{
-    Result,
+    Error, Result,
     http::HttpClient,
+    resources::{Listable, Retrievable, payments::Payment},
     types::{
-        CaptureMethod, Currency, Metadata, PaymentIntentId, PaymentMethod, PaymentMethodOptions,
-        Timestamp,
+        CaptureMethod, Currency, ExpandParams, Expandable, List, ListParams, ListStream, Metadata,
+        Money, ObjectType, PaymentId, PaymentIntentId, PaymentMethod, PaymentMethodOptions,
+        RangeQuery, Resource, Timestamp,
+        pagination::{Paginate, paginate},
     },
 };
 use payrex_derive::{Payrex, payrex_attr};
 use serde::{Deserialize, Serialize};
-use std::sync::Arc;
+use std::{future::Future, sync::Arc};
 
 /// A [`PaymentIntent`] tracks the customer's payment lifecycle, keeping track of any failed payment
 /// attempts and ensuring the customer is only charged once. Create one [`PaymentIntent`] whenever your
@@ -50,6 +53,64 @@ impl PaymentIntents {
             .await
     }
 
+    /// Retrieve a [`PaymentIntent`] resource by ID with related resources such as
+    /// `latest_payment` expanded inline.
+    ///
+    /// Endpoint: `GET /payment_intents/:id`
+    ///
+    /// [API Reference](https://docs.payrexhq.com/docs/api/payment_intents/retrieve)
+    pub async fn retrieve_with_params(
+        &self,
+        id: &PaymentIntentId,
+        params: ExpandParams,
+    ) -> Result<PaymentIntent> {
+        self.http
+            .get_with_params(&format!("/payment_intents/{}", id.as_str()), &params)
+            .await
+    }
+
+    /// Updates a [`PaymentIntent`] resource. Only payment intents that are not yet confirmed can be
+    /// updated.
+    ///
+    /// Endpoint: `PUT /payment_intents/:id`
+    ///
+    /// [API Reference](https://docs.payrexhq.com/docs/api/payment_intents/update)
+    pub async fn update(
+        &self,
+        id: &PaymentIntentId,
+        params: UpdatePaymentIntent,
+    ) -> Result<PaymentIntent> {
+        self.http
+            .put(&format!("/payment_intents/{}", id.as_str()), &params)
+            .await
+    }
+
+    /// List [`PaymentIntent`] resources.
+    ///
+    /// Endpoint: `GET /payment_intents`
+    ///
+    /// [API Reference](https://docs.payrexhq.com/docs/api/payment_intents/list)
+    pub async fn list(
+        &self,
+        params: Option<PaymentIntentListParams>,
+    ) -> Result<List<PaymentIntent>> {
+        self.http.get_with_params("/payment_intents", &params).await
+    }
+
+    /// Lists every [`PaymentIntent`] resource, fetching the next page lazily as the stream is
+    /// consumed.
+    ///
+    /// Endpoint: `GET /payment_intents`
+    ///
+    /// [API Reference](https://docs.payrexhq.com/docs/api/payment_intents/list)
+    pub fn list_auto(&self, params: PaymentIntentListParams) -> ListStream<PaymentIntent> {
+        let http = Arc::clone(&self.http);
+        paginate(params, move |params: PaymentIntentListParams| {
+            let http = Arc::clone(&http);
+            async move { http.get_with_params("/payment_intents", &params).await }
+        })
+    }
+
     /// Cancels a [`PaymentIntent`] resource. A payment intent with a status of `canceled` means your
     /// customer cannot proceed with paying the particular payment intent.
     ///
@@ -79,6 +140,40 @@ impl PaymentIntents {
             )
             .await
     }
+
+    /// Captures a [`PaymentIntent`] resource after validating the requested amount against its
+    /// `amount_capturable`. Returns [`Error::CaptureAmountExceeded`] without calling PayRex when the
+    /// amount is larger than what can be captured.
+    ///
+    /// Endpoint: `POST /payment_intents/:id/capture`
+    ///
+    /// [API Reference](https://docs.payrexhq.com/docs/api/payment_intents/capture)
+    pub async fn capture_checked(
+        &self,
+        payment_intent: &PaymentIntent,
+        params: CapturePaymentIntent,
+    ) -> Result<PaymentIntent> {
+        params.validate(payment_intent)?;
+        self.capture(&payment_intent.id, params).await
+    }
+}
+
+impl Retrievable for PaymentIntents {
+    type Id = PaymentIntentId;
+    type Item = PaymentIntent;
+
+    fn retrieve(&self, id: &Self::Id) -> impl Future<Output = Result<Self::Item>> + Send {
+        Self::retrieve(self, id)
+    }
+}
+
+impl Listable for PaymentIntents {
+    type Item = PaymentIntent;
+    type Params = PaymentIntentListParams;
+
+    fn list(&self, params: Self::Params) -> impl Future<Output = Result<List<Self::Item>>> + Send {
+        Self::list(self, Some(params))
+    }
 }
 
 /// If this attribute is present, it tells you what actions you need to take so that your customer
@@ -114,6 +209,7 @@ pub struct PaymentError {
 ///
 /// A [`PaymentIntent`] transitions through multiple statuses throughout its lifetime via Payrex.JS until it creates, at most, one successful payment.
 #[payrex_attr(
+    resource,
     timestamp,
     livemode,
     metadata,
@@ -147,9 +243,10 @@ pub struct PaymentIntent {
     ///key. The client secret can be used to complete a payment from your client application.
     pub client_secret: String,
 
-    /// The `Payment` ID of the latest successful payment created by the [`PaymentIntent`].
+    /// The latest successful payment created by the [`PaymentIntent`]. Only the ID is included
+    /// unless `latest_payment` is expanded.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub latest_payment: Option<String>,
+    pub latest_payment: Option<Expandable<PaymentId, Payment>>,
 
     /// The error returned in case of a failed payment attempt.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -192,8 +289,20 @@ pub struct PaymentIntent {
     pub capture_before_at: Option<Timestamp>,
 }
 
+impl Resource for PaymentIntent {
+    type Id = PaymentIntentId;
+
+    fn id(&self) -> &Self::Id {
+        &self.id
+    }
+
+    fn object_type() -> ObjectType {
+        ObjectType::PaymentIntent
+    }
+}
+
 /// The status of a [`PaymentIntent`] describes the current state of the payment process.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum PaymentIntentStatus {
     /// Awaiting a valid payment method to be attached.
@@ -219,6 +328,10 @@ pub enum PaymentIntentStatus {
 
     /// The payment was successful.
     Succeeded,
+
+    /// A status not known to this version of the SDK, holding the original value.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 /// Query parameters when creating a payment intent.
@@ -266,6 +379,73 @@ pub struct CreatePaymentIntent {
     pub return_url: Option<String>,
 }
 
+impl CreatePaymentIntent {
+    /// Creates a new [`CreatePaymentIntent`] collecting `amount`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidRequest`] if the amount is negative.
+    pub fn from_money(
+        payment_methods: impl Into<Vec<PaymentMethod>>,
+        amount: Money,
+    ) -> Result<Self> {
+        Ok(Self::new(
+            payment_methods,
+            amount.to_minor()?,
+            amount.currency().clone(),
+        ))
+    }
+}
+
+/// Query parameters when updating a payment intent.
+///
+/// [Reference](https://docs.payrexhq.com/docs/api/payment_intents/update#parameters)
+#[payrex_attr(metadata, amount = true, description = "payment_intent")]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, Payrex)]
+pub struct UpdatePaymentIntent {
+    /// The list of payment methods allowed to be processed by the [`PaymentIntent`]. Possible values
+    /// are `card`, `gcash`, `maya`, and `qrph`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[payrex(description = "Sets the payment methods when updating a payment intent.")]
+    pub payment_methods: Option<Vec<PaymentMethod>>,
+
+    /// A set of key-value pairs that can modify the behavior of the payment method attached to the
+    /// payment intent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[payrex(description = "Sets the payment method options when updating a payment intent.")]
+    pub payment_method_options: Option<PaymentMethodOptions>,
+}
+
+/// Query parameters when listing payment intents.
+///
+/// [Reference](https://docs.payrexhq.com/docs/api/payment_intents/list#parameters)
+#[payrex_attr(metadata)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, Payrex)]
+pub struct PaymentIntentListParams {
+    /// Baseline pagination fields such as `limit`, `before`, and `after`.
+    #[serde(flatten)]
+    pub list_params: ListParams,
+
+    /// Filters payment intents by their latest status.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[payrex(description = "Sets the status in query params when listing payment intents.")]
+    pub status: Option<PaymentIntentStatus>,
+
+    /// Filters payment intents by the time they were created, measured in seconds since the Unix
+    /// epoch.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[payrex(
+        description = "Sets the creation time range in query params when listing payment intents."
+    )]
+    pub created_at: Option<RangeQuery<Timestamp>>,
+}
+
+impl Paginate for PaymentIntentListParams {
+    fn list_params_mut(&mut self) -> &mut ListParams {
+        &mut self.list_params
+    }
+}
+
 /// Query parameters when capturing a payment intent.
 ///
 /// [Reference](https://docs.payrexhq.com/docs/api/payment_intents/capture#parameters)
@@ -274,11 +454,51 @@ pub struct CreatePaymentIntent {
 pub struct CapturePaymentIntent {}
 
 impl CapturePaymentIntent {
-    /// Creates a new [`CapturePaymentIntent`] with the specified amount.
+    /// Creates a new [`CapturePaymentIntent`] with the specified amount. Capturing less than the
+    /// authorized amount releases the uncaptured remainder back to the customer.
     #[must_use]
     pub const fn new(amount: u64) -> Self {
         Self { amount }
     }
+
+    /// Creates a new [`CapturePaymentIntent`] capturing `amount`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidRequest`] if the amount is negative.
+    pub fn from_money(amount: Money) -> Result<Self> {
+        Ok(Self::new(amount.to_minor()?))
+    }
+
+    /// Creates a [`CapturePaymentIntent`] that captures the whole `amount_capturable` of the
+    /// [`PaymentIntent`].
+    #[must_use]
+    pub const fn full(payment_intent: &PaymentIntent) -> Self {
+        Self::new(payment_intent.amount_capturable)
+    }
+
+    /// Checks the capture amount against the `amount_capturable` of the [`PaymentIntent`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidRequest`] if the amount is zero, and
+    /// [`Error::CaptureAmountExceeded`] if it exceeds the capturable amount.
+    pub fn validate(&self, payment_intent: &PaymentIntent) -> Result<()> {
+        if self.amount == 0 {
+            return Err(Error::InvalidRequest(
+                "capture amount must be greater than zero".to_string(),
+            ));
+        }
+
+        if self.amount > payment_intent.amount_capturable {
+            return Err(Error::CaptureAmountExceeded {
+                requested: self.amount,
+                capturable: payment_intent.amount_capturable,
+            });
+        }
+
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -341,12 +561,71 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_create_payment_intent_from_money() {
+        let params = CreatePaymentIntent::from_money(
+            [PaymentMethod::Card],
+            Money::new(10_050, Currency::PHP),
+        )
+        .unwrap();
+        let json = serde_json::to_value(&params).unwrap();
+
+        assert_eq!(json["amount"], 10_050);
+        assert_eq!(json["currency"], "PHP");
+        assert!(
+            CreatePaymentIntent::from_money([PaymentMethod::Card], Money::new(-1, Currency::PHP))
+                .is_err()
+        );
+    }
+
     #[test]
     fn test_capture_payment_intent() {
         let params = CapturePaymentIntent::new(5000);
         assert_eq!(params.amount, 5000);
     }
 
+    #[test]
+    fn test_capture_payment_intent_from_money() {
+        let params = CapturePaymentIntent::from_money(Money::new(5000, Currency::PHP)).unwrap();
+        assert_eq!(params.amount, 5000);
+        assert!(CapturePaymentIntent::from_money(Money::new(-1, Currency::PHP)).is_err());
+    }
+
+    #[test]
+    fn test_capture_payment_intent_serialization() {
+        let params = CapturePaymentIntent::new(2500);
+        let json = serde_json::to_value(&params).unwrap();
+
+        assert_eq!(json["amount"], 2500);
+    }
+
+    #[test]
+    fn test_update_payment_intent_builder() {
+        use PaymentMethod::*;
+        let params = UpdatePaymentIntent::new()
+            .amount(20000)
+            .description("Updated order")
+            .payment_methods(vec![Card, Maya]);
+        let json = serde_json::to_value(&params).unwrap();
+
+        assert_eq!(json["amount"], 20000);
+        assert_eq!(json["description"], "Updated order");
+        assert_eq!(json["payment_methods"][1], "maya");
+        assert!(json.get("metadata").is_none());
+        assert!(json.get("payment_method_options").is_none());
+    }
+
+    #[test]
+    fn test_payment_intent_list_params_encoding() {
+        let params = PaymentIntentListParams::new()
+            .status(PaymentIntentStatus::AwaitingPaymentMethod)
+            .created_at(RangeQuery::new().gte(Timestamp::from_unix(1_700_000_000)));
+        let encoded = serde_qs::to_string(&params).unwrap();
+
+        assert!(encoded.contains("status=awaiting_payment_method"));
+        assert!(encoded.contains("created_at[gte]=1700000000"));
+    }
+
     #[test]
     fn test_payment_intent_status_serialization() {
         use serde_json;
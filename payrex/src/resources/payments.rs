@@ -5,12 +5,16 @@
 use crate::{
     Result,
     http::HttpClient,
-    resources::customers::Customer,
-    types::{Currency, Metadata, PaymentId, PaymentIntentId, PaymentMethod, Timestamp},
+    resources::{Listable, Retrievable, customers::Customer, payment_intents::PaymentIntent},
+    types::{
+        Currency, CustomerId, ExpandParams, Expandable, List, ListParams, ListStream, Metadata,
+        ObjectType, PaymentId, PaymentIntentId, PaymentMethod, RangeQuery, Resource, Timestamp,
+        pagination::{Paginate, paginate},
+    },
 };
 use payrex_derive::{Payrex, payrex_attr};
 use serde::{Deserialize, Serialize};
-use std::sync::Arc;
+use std::{future::Future, sync::Arc};
 
 /// Payments API
 #[derive(Clone)]
@@ -33,6 +37,44 @@ impl Payments {
         self.http.get(&format!("/payments/{}", id.as_str())).await
     }
 
+    /// Retrieve a Payment resource by ID with related resources expanded inline, e.g.
+    /// `payment_intent_id` to get the full [`PaymentIntent`].
+    ///
+    /// Endpoint: `GET /payments/:id`
+    ///
+    /// [API Reference](https://docs.payrexhq.com/docs/api/payments/retrieve)
+    pub async fn retrieve_with_params(
+        &self,
+        id: &PaymentId,
+        params: ExpandParams,
+    ) -> Result<Payment> {
+        self.http
+            .get_with_params(&format!("/payments/{}", id.as_str()), &params)
+            .await
+    }
+
+    /// List Payment resources.
+    ///
+    /// Endpoint: `GET /payments`
+    ///
+    /// [API Reference](https://docs.payrexhq.com/docs/api/payments/list)
+    pub async fn list(&self, params: Option<PaymentListParams>) -> Result<List<Payment>> {
+        self.http.get_with_params("/payments", &params).await
+    }
+
+    /// Lists every Payment resource, fetching the next page lazily as the stream is consumed.
+    ///
+    /// Endpoint: `GET /payments`
+    ///
+    /// [API Reference](https://docs.payrexhq.com/docs/api/payments/list)
+    pub fn list_auto(&self, params: PaymentListParams) -> ListStream<Payment> {
+        let http = Arc::clone(&self.http);
+        paginate(params, move |params: PaymentListParams| {
+            let http = Arc::clone(&http);
+            async move { http.get_with_params("/payments", &params).await }
+        })
+    }
+
     /// Update a Payment resource by ID.
     ///
     /// Endpoint: `PUT /payments/:id`
@@ -45,12 +87,31 @@ impl Payments {
     }
 }
 
+impl Retrievable for Payments {
+    type Id = PaymentId;
+    type Item = Payment;
+
+    fn retrieve(&self, id: &Self::Id) -> impl Future<Output = Result<Self::Item>> + Send {
+        Self::retrieve(self, id)
+    }
+}
+
+impl Listable for Payments {
+    type Item = Payment;
+    type Params = PaymentListParams;
+
+    fn list(&self, params: Self::Params) -> impl Future<Output = Result<List<Self::Item>>> + Send {
+        Self::list(self, Some(params))
+    }
+}
+
 /// The Payment resource represents an individual attempt to move money to your PayRex merchant
 /// account balance.
 ///
 /// When your customer successfully completed a transaction, a Payment resource represents the
 /// actual payment of your customer.
 #[payrex_attr(
+    resource,
     timestamp,
     metadata,
     livemode,
@@ -82,8 +143,9 @@ pub struct Payment {
     /// cents. If the `net_amount` is ₱ 120.50, the `net_amount` of the Payment should be 12050.
     pub net_amount: u64,
 
-    /// The ID of the payment intent resource that generated the Payment resource.
-    pub payment_intent_id: PaymentIntentId,
+    /// The payment intent resource that generated the Payment resource. Only the ID is included
+    /// unless `payment_intent_id` is expanded.
+    pub payment_intent_id: Expandable<PaymentIntentId, PaymentIntent>,
 
     /// The status of the Payment. Possible values are `paid`, or `failed`.
     pub status: PaymentStatus,
@@ -102,6 +164,18 @@ pub struct Payment {
     pub refunded: bool,
 }
 
+impl Resource for Payment {
+    type Id = PaymentId;
+
+    fn id(&self) -> &Self::Id {
+        &self.id
+    }
+
+    fn object_type() -> ObjectType {
+        ObjectType::Payment
+    }
+}
+
 /// Contains the billing information of the customer.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Billing {
@@ -171,7 +245,7 @@ pub struct PaymentMethodTypesCard {
 }
 
 /// Represents the status of a payment
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum PaymentStatus {
     /// The payment transaction is successfully paid
@@ -179,15 +253,62 @@ pub enum PaymentStatus {
 
     /// The payment transaction failed
     Failed,
+
+    /// A status not known to this version of the SDK, holding the original value.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
-/// Query parameters when updating a payment.
+/// Query parameters when updating a payment. PayRex only allows the `description` and `metadata`
+/// of a Payment to be changed; fields left unset are not sent.
 ///
 /// [Reference](https://docs.payrexhq.com/docs/api/payments/update#parameters)
 #[payrex_attr(metadata, description = "payment")]
 #[derive(Debug, Default, Clone, Serialize, Deserialize, Payrex)]
 pub struct UpdatePayment {}
 
+/// Query parameters when listing payments.
+///
+/// [Reference](https://docs.payrexhq.com/docs/api/payments/list#parameters)
+#[payrex_attr(metadata)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, Payrex)]
+pub struct PaymentListParams {
+    /// Baseline pagination fields such as `limit`, `before`, and `after`.
+    #[serde(flatten)]
+    pub list_params: ListParams,
+
+    /// Filters payments generated by a payment intent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[payrex(description = "Sets the payment intent ID in query params when listing payments.")]
+    pub payment_intent_id: Option<PaymentIntentId>,
+
+    /// Filters payments made by a customer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[payrex(description = "Sets the customer ID in query params when listing payments.")]
+    pub customer_id: Option<CustomerId>,
+
+    /// Filters payments by their status.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[payrex(description = "Sets the status in query params when listing payments.")]
+    pub status: Option<PaymentStatus>,
+
+    /// Filters payments by the payment method used to complete them.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[payrex(description = "Sets the payment method in query params when listing payments.")]
+    pub payment_method: Option<PaymentMethod>,
+
+    /// Filters payments by the time they were created, measured in seconds since the Unix epoch.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[payrex(description = "Sets the creation time range in query params when listing payments.")]
+    pub created_at: Option<RangeQuery<Timestamp>>,
+}
+
+impl Paginate for PaymentListParams {
+    fn list_params_mut(&mut self) -> &mut ListParams {
+        &mut self.list_params
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -203,6 +324,31 @@ mod tests {
 
         assert_eq!(params.description, Some("Test payment".to_string()));
         assert_eq!(params.metadata, Some(metadata));
+
+        let json = serde_json::to_value(UpdatePayment::new().description("Only this")).unwrap();
+        assert_eq!(json, serde_json::json!({ "description": "Only this" }));
+    }
+
+    #[test]
+    fn test_payment_list_params_encoding() {
+        let params = PaymentListParams::new()
+            .payment_intent_id(PaymentIntentId::new("pi_123"))
+            .customer_id(CustomerId::new("cus_123"))
+            .status(PaymentStatus::Paid)
+            .payment_method(PaymentMethod::GCash)
+            .created_at(
+                RangeQuery::new()
+                    .gte(Timestamp::from_unix(1_700_000_000))
+                    .lt(Timestamp::from_unix(1_700_086_400)),
+            );
+        let encoded = serde_qs::to_string(&params).unwrap();
+
+        assert!(encoded.contains("payment_intent_id=pi_123"));
+        assert!(encoded.contains("customer_id=cus_123"));
+        assert!(encoded.contains("status=paid"));
+        assert!(encoded.contains("payment_method=gcash"));
+        assert!(encoded.contains("created_at[gte]=1700000000"));
+        assert!(encoded.contains("created_at[lt]=1700086400"));
     }
 
     #[test]
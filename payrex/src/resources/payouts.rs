@@ -5,11 +5,16 @@
 use crate::{
     Result,
     http::HttpClient,
-    types::{List, ListParams, PayoutId, PayoutTransactionId, Timestamp},
+    resources::{Listable, Retrievable},
+    types::{
+        List, ListParams, ListStream, ObjectType, PayoutId, PayoutTransactionId, RangeQuery,
+        Resource, Timestamp,
+        pagination::{Paginate, paginate},
+    },
 };
-use payrex_derive::payrex_attr;
+use payrex_derive::{Payrex, payrex_attr};
 use serde::{Deserialize, Serialize};
-use std::sync::Arc;
+use std::{future::Future, sync::Arc};
 
 /// Payouts API
 #[derive(Clone)]
@@ -23,6 +28,38 @@ impl Payouts {
         Self { http }
     }
 
+    /// Retrieves a Payout resource by ID.
+    ///
+    /// Endpoint: `GET /payouts/:id`
+    ///
+    /// [API Reference](https://docs.payrexhq.com/docs/api/payouts/retrieve)
+    pub async fn retrieve(&self, id: &PayoutId) -> Result<Payout> {
+        self.http.get(&format!("/payouts/{}", id.as_str())).await
+    }
+
+    /// Lists Payout resources.
+    ///
+    /// Endpoint: `GET /payouts`
+    ///
+    /// [API Reference](https://docs.payrexhq.com/docs/api/payouts/list)
+    pub async fn list(&self, params: Option<PayoutListParams>) -> Result<List<Payout>> {
+        self.http.get_with_params("/payouts", &params).await
+    }
+
+    /// Lists every Payout resource, fetching the next page lazily as the stream is consumed.
+    /// Combine it with [`Payouts::list_transactions_auto`] to walk the transactions of each payout.
+    ///
+    /// Endpoint: `GET /payouts`
+    ///
+    /// [API Reference](https://docs.payrexhq.com/docs/api/payouts/list)
+    pub fn list_auto(&self, params: PayoutListParams) -> ListStream<Payout> {
+        let http = Arc::clone(&self.http);
+        paginate(params, move |params: PayoutListParams| {
+            let http = Arc::clone(&http);
+            async move { http.get_with_params("/payouts", &params).await }
+        })
+    }
+
     /// List payout transaction resources.
     ///
     /// Endpoint: `GET /payouts/:id/transactions`
@@ -37,12 +74,50 @@ impl Payouts {
             .get_with_params(&format!("/payouts/{}/transactions", id.as_str()), &params)
             .await
     }
+
+    /// Lists every transaction of a payout, fetching the next page lazily as the stream is
+    /// consumed.
+    ///
+    /// Endpoint: `GET /payouts/:id/transactions`
+    ///
+    /// [API Reference](https://docs.payrexhq.com/docs/api/payout_transactions/list)
+    pub fn list_transactions_auto(
+        &self,
+        id: &PayoutId,
+        params: ListParams,
+    ) -> ListStream<PayoutTransaction> {
+        let http = Arc::clone(&self.http);
+        let path = format!("/payouts/{}/transactions", id.as_str());
+        paginate(params, move |params: ListParams| {
+            let http = Arc::clone(&http);
+            let path = path.clone();
+            async move { http.get_with_params(&path, &params).await }
+        })
+    }
+}
+
+impl Retrievable for Payouts {
+    type Id = PayoutId;
+    type Item = Payout;
+
+    fn retrieve(&self, id: &Self::Id) -> impl Future<Output = Result<Self::Item>> + Send {
+        Self::retrieve(self, id)
+    }
+}
+
+impl Listable for Payouts {
+    type Item = Payout;
+    type Params = PayoutListParams;
+
+    fn list(&self, params: Self::Params) -> impl Future<Output = Result<List<Self::Item>>> + Send {
+        Self::list(self, Some(params))
+    }
 }
 
 /// The Payout resource is created when you are scheduled to receive money from PayRex. Payouts are
 /// made depending on the payout schedule for your PayRex merchant account. A Payout resource
 /// represents a net amount of money settled to your nominated bank account.
-#[payrex_attr(livemode, timestamp, amount = false)]
+#[payrex_attr(resource, livemode, timestamp, amount = false)]
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Payout {
     /// Unique identifier for the resource. The prefix is `po_`.
@@ -65,8 +140,20 @@ pub struct Payout {
     pub status: PayoutStatus,
 }
 
+impl Resource for Payout {
+    type Id = PayoutId;
+
+    fn id(&self) -> &Self::Id {
+        &self.id
+    }
+
+    fn object_type() -> ObjectType {
+        ObjectType::Payout
+    }
+}
+
 /// The status of a Payout.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum PayoutStatus {
     /// The payout is currently pending.
@@ -80,6 +167,39 @@ pub enum PayoutStatus {
 
     /// The payout was cancelled.
     Cancelled,
+
+    /// The payout was settled to the bank account.
+    Successful,
+
+    /// A status not known to this version of the SDK, holding the original value.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// Query parameters when listing payouts.
+///
+/// [Reference](https://docs.payrexhq.com/docs/api/payouts/list#parameters)
+#[derive(Debug, Default, Clone, Serialize, Deserialize, Payrex)]
+pub struct PayoutListParams {
+    /// Baseline pagination fields such as `limit`, `before`, and `after`.
+    #[serde(flatten)]
+    pub list_params: ListParams,
+
+    /// Filters payouts by their status.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[payrex(description = "Sets the status in query params when listing payouts.")]
+    pub status: Option<PayoutStatus>,
+
+    /// Filters payouts by the time they were created, measured in seconds since the Unix epoch.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[payrex(description = "Sets the creation time range in query params when listing payouts.")]
+    pub created_at: Option<RangeQuery<Timestamp>>,
+}
+
+impl Paginate for PayoutListParams {
+    fn list_params_mut(&mut self) -> &mut ListParams {
+        &mut self.list_params
+    }
 }
 
 /// The payout destination holds the information of the bank account that you nominated for
@@ -112,7 +232,7 @@ pub enum PayoutTransactionType {
 
 /// The Payment Transaction resource represents every line item of a Payout. Every Payout
 /// Transaction belongs to a Payout resource.
-#[payrex_attr(timestamp, amount = false)]
+#[payrex_attr(resource, timestamp, amount = false)]
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct PayoutTransaction {
     /// Unique identifier for the resource. The prefix is `po_txn_`.
@@ -147,6 +267,18 @@ pub struct PayoutTransaction {
     pub transaction_type: PayoutTransactionType,
 }
 
+impl Resource for PayoutTransaction {
+    type Id = PayoutTransactionId;
+
+    fn id(&self) -> &Self::Id {
+        &self.id
+    }
+
+    fn object_type() -> ObjectType {
+        ObjectType::PayoutTransaction
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -170,6 +302,21 @@ mod tests {
         let status = PayoutStatus::Cancelled;
         let json = serde_json::to_string(&status).unwrap();
         assert_eq!(json, "\"cancelled\"");
+
+        let status = PayoutStatus::Successful;
+        let json = serde_json::to_string(&status).unwrap();
+        assert_eq!(json, "\"successful\"");
+    }
+
+    #[test]
+    fn test_payout_list_params_encoding() {
+        let params = PayoutListParams::new()
+            .status(PayoutStatus::InTransit)
+            .created_at(RangeQuery::new().gt(Timestamp::from_unix(1_700_000_000)));
+        let encoded = serde_qs::to_string(&params).unwrap();
+
+        assert!(encoded.contains("status=in_transit"));
+        assert!(encoded.contains("created_at[gt]=1700000000"));
     }
 
     #[test]
@@ -190,6 +337,7 @@ mod tests {
             bank_name: "Test Bank".to_string(),
         };
         let payout = Payout {
+            resource: ObjectType::Payout,
             id: PayoutId::new("po_123"),
             amount: 5000,
             destination: Some(dest.clone()),
@@ -198,6 +346,7 @@ mod tests {
             status: PayoutStatus::Pending,
             created_at: Timestamp::from_unix(1_610_000_000),
             updated_at: Timestamp::from_unix(1_610_001_000),
+            extra: serde_json::Map::new(),
         };
         let json = serde_json::to_value(&payout).unwrap();
         assert_eq!(json["id"], "po_123");
@@ -215,6 +364,7 @@ mod tests {
     #[test]
     fn test_payout_transaction_serialization() {
         let tx = PayoutTransaction {
+            resource: ObjectType::PayoutTransaction,
             id: PayoutTransactionId::new("po_txn_abc"),
             amount: 500,
             net_amount: 490,
@@ -222,6 +372,7 @@ mod tests {
             transaction_type: PayoutTransactionType::Refund,
             created_at: Timestamp::from_unix(1_610_002_000),
             updated_at: Timestamp::from_unix(1_610_002_000),
+            extra: serde_json::Map::new(),
         };
         let json = serde_json::to_value(&tx).unwrap();
         assert_eq!(json["id"], "po_txn_abc");
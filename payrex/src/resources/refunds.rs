@@ -3,13 +3,22 @@
 //! Refunds allow you to return money to a customer.
 
 use crate::{
-    Result,
+    Error, Result,
     http::HttpClient,
-    types::{Currency, Metadata, PaymentId, RefundId, Timestamp},
+    resources::{
+        Listable, Retrievable,
+        payments::{Payment, PaymentStatus},
+    },
+    types::{
+        Currency, List, ListParams, ListStream, Metadata, Money, ObjectType, PaymentId, RangeQuery,
+        RefundId, Resource, Timestamp,
+        pagination::{Paginate, paginate},
+    },
 };
+use futures::TryStreamExt;
 use payrex_derive::{Payrex, payrex_attr};
 use serde::{Deserialize, Serialize};
-use std::sync::Arc;
+use std::{future::Future, sync::Arc};
 
 /// Refunds API
 #[derive(Clone)]
@@ -32,6 +41,84 @@ impl Refunds {
         self.http.post("/refunds", &params).await
     }
 
+    /// Creates a Refund resource after checking that the amount does not exceed what is left to
+    /// refund on the [`Payment`]. The existing refunds of the payment are listed first, and
+    /// [`Error::RefundAmountExceeded`] is returned without calling the create endpoint when the
+    /// refund would exceed the remaining amount. Payments that are not
+    /// [`PaymentStatus::Paid`] are rejected with [`Error::InvalidRequest`] before any request.
+    ///
+    /// Endpoint: `POST /refunds`
+    ///
+    /// [API Reference](https://docs.payrexhq.com/docs/api/refunds/create)
+    pub async fn create_checked(&self, payment: &Payment, params: CreateRefund) -> Result<Refund> {
+        if params.payment_id != payment.id {
+            return Err(Error::InvalidRequest(format!(
+                "refund targets payment {} but payment {} was given",
+                params.payment_id, payment.id
+            )));
+        }
+        if payment.status != PaymentStatus::Paid {
+            return Err(Error::InvalidRequest(format!(
+                "payment {} cannot be refunded while its status is {:?}",
+                payment.id, payment.status
+            )));
+        }
+
+        let refundable = self.refundable_amount(payment).await?;
+        if params.amount > refundable {
+            return Err(Error::RefundAmountExceeded {
+                requested: params.amount,
+                refundable,
+            });
+        }
+
+        self.create(params).await
+    }
+
+    /// Retrieves a Refund resource by ID.
+    ///
+    /// Endpoint: `GET /refunds/:id`
+    ///
+    /// [API Reference](https://docs.payrexhq.com/docs/api/refunds/retrieve)
+    pub async fn retrieve(&self, id: &RefundId) -> Result<Refund> {
+        self.http.get(&format!("/refunds/{}", id.as_str())).await
+    }
+
+    /// Lists Refund resources.
+    ///
+    /// Endpoint: `GET /refunds`
+    ///
+    /// [API Reference](https://docs.payrexhq.com/docs/api/refunds/list)
+    pub async fn list(&self, params: Option<RefundListParams>) -> Result<List<Refund>> {
+        self.http.get_with_params("/refunds", &params).await
+    }
+
+    /// Lists every Refund resource, fetching the next page lazily as the stream is consumed.
+    ///
+    /// Endpoint: `GET /refunds`
+    ///
+    /// [API Reference](https://docs.payrexhq.com/docs/api/refunds/list)
+    pub fn list_auto(&self, params: RefundListParams) -> ListStream<Refund> {
+        let http = Arc::clone(&self.http);
+        paginate(params, move |params: RefundListParams| {
+            let http = Arc::clone(&http);
+            async move { http.get_with_params("/refunds", &params).await }
+        })
+    }
+
+    /// Computes the amount of the [`Payment`] that can still be refunded by listing every refund
+    /// made against it. See [`refundable_amount`] for how the refunds are counted.
+    ///
+    /// Endpoint: `GET /refunds`
+    ///
+    /// [API Reference](https://docs.payrexhq.com/docs/api/refunds/list)
+    pub async fn refundable_amount(&self, payment: &Payment) -> Result<u64> {
+        let params = RefundListParams::new().payment_id(payment.id.clone());
+        let refunds: Vec<Refund> = self.list_auto(params).try_collect().await?;
+
+        Ok(refundable_amount(payment, &refunds))
+    }
+
     /// Updates a Refund resource.
     ///
     /// Endpoint: `PUT /refunds/:id`
@@ -44,8 +131,57 @@ impl Refunds {
     }
 }
 
+impl Retrievable for Refunds {
+    type Id = RefundId;
+    type Item = Refund;
+
+    fn retrieve(&self, id: &Self::Id) -> impl Future<Output = Result<Self::Item>> + Send {
+        Self::retrieve(self, id)
+    }
+}
+
+impl Listable for Refunds {
+    type Item = Refund;
+    type Params = RefundListParams;
+
+    fn list(&self, params: Self::Params) -> impl Future<Output = Result<List<Self::Item>>> + Send {
+        Self::list(self, Some(params))
+    }
+}
+
+/// Computes the amount of the [`Payment`] that can still be refunded given its refunds.
+///
+/// Pending and succeeded refunds of the payment count against its amount, while failed refunds,
+/// refunds with an unknown status and refunds of other payments are ignored. The larger of that
+/// total and the `amount_refunded` reported on the payment is subtracted, so refunds missing from
+/// `refunds` are still accounted for. Payments that are not [`PaymentStatus::Paid`] have nothing
+/// to refund, and the result never goes below zero.
+#[must_use]
+pub fn refundable_amount(payment: &Payment, refunds: &[Refund]) -> u64 {
+    if payment.status != PaymentStatus::Paid {
+        return 0;
+    }
+
+    let refunded: u64 = refunds
+        .iter()
+        .filter(|refund| {
+            refund.payment_id == payment.id
+                && matches!(
+                    refund.status,
+                    RefundStatus::Pending | RefundStatus::Succeeded
+                )
+        })
+        .map(|refund| refund.amount)
+        .sum();
+
+    payment
+        .amount
+        .saturating_sub(refunded.max(payment.amount_refunded))
+}
+
 /// A Refund resource represents a refunded amount of a paid payment.
 #[payrex_attr(
+    resource,
     timestamp,
     metadata,
     livemode,
@@ -78,8 +214,20 @@ pub struct Refund {
     pub payment_id: PaymentId,
 }
 
+impl Resource for Refund {
+    type Id = RefundId;
+
+    fn id(&self) -> &Self::Id {
+        &self.id
+    }
+
+    fn object_type() -> ObjectType {
+        ObjectType::Refund
+    }
+}
+
 /// The latest status of a Refund.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum RefundStatus {
     /// Refund status when a refund is pending.
@@ -90,6 +238,10 @@ pub enum RefundStatus {
 
     /// Refund status when a refund failed.
     Failed,
+
+    /// A status not known to this version of the SDK, holding the original value.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 /// The reason of a Refund.
@@ -148,6 +300,22 @@ pub struct CreateRefund {
     pub remarks: Option<String>,
 }
 
+impl CreateRefund {
+    /// Creates a new [`CreateRefund`] refunding `amount` of the payment.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidRequest`] if the amount is negative.
+    pub fn from_money(payment_id: PaymentId, reason: RefundReason, amount: Money) -> Result<Self> {
+        Ok(Self::new(
+            payment_id,
+            reason,
+            amount.to_minor()?,
+            amount.currency().clone(),
+        ))
+    }
+}
+
 /// Query parameters when updating a refund.
 ///
 /// [Reference](https://docs.payrexhq.com/docs/api/refunds/update#parameters)
@@ -155,6 +323,37 @@ pub struct CreateRefund {
 #[derive(Debug, Clone, Default, Serialize, Deserialize, Payrex)]
 pub struct UpdateRefund {}
 
+/// Query parameters when listing refunds.
+///
+/// [Reference](https://docs.payrexhq.com/docs/api/refunds/list#parameters)
+#[derive(Debug, Default, Clone, Serialize, Deserialize, Payrex)]
+pub struct RefundListParams {
+    /// Baseline pagination fields such as `limit`, `before`, and `after`.
+    #[serde(flatten)]
+    pub list_params: ListParams,
+
+    /// Filters refunds made against a payment.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[payrex(description = "Sets the payment ID in query params when listing refunds.")]
+    pub payment_id: Option<PaymentId>,
+
+    /// Filters refunds by their latest status.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[payrex(description = "Sets the status in query params when listing refunds.")]
+    pub status: Option<RefundStatus>,
+
+    /// Filters refunds by the time they were created, measured in seconds since the Unix epoch.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[payrex(description = "Sets the creation time range in query params when listing refunds.")]
+    pub created_at: Option<RangeQuery<Timestamp>>,
+}
+
+impl Paginate for RefundListParams {
+    fn list_params_mut(&mut self) -> &mut ListParams {
+        &mut self.list_params
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -203,6 +402,7 @@ mod tests {
         metadata.insert("key", "value");
 
         let refund = Refund {
+            resource: ObjectType::Refund,
             id: RefundId::new("re_123"),
             amount: 1000,
             currency: Currency::PHP,
@@ -215,6 +415,7 @@ mod tests {
             metadata: Some(metadata.clone()),
             created_at: Timestamp::from_unix(1_620_000_000),
             updated_at: Timestamp::from_unix(1_620_001_000),
+            extra: serde_json::Map::new(),
         };
 
         let json = serde_json::to_value(&refund).unwrap();
@@ -256,6 +457,19 @@ mod tests {
         assert_eq!(params.description, Some("desc".to_string()));
     }
 
+    #[test]
+    fn test_create_refund_from_money() {
+        let params = CreateRefund::from_money(
+            PaymentId::new("pay_abc"),
+            RefundReason::Others,
+            Money::new(500, Currency::PHP),
+        )
+        .unwrap();
+
+        assert_eq!(params.amount, 500);
+        assert_eq!(params.currency, Currency::PHP);
+    }
+
     #[test]
     fn test_update_refund_serialization() {
         let mut metadata = Metadata::new();
@@ -266,4 +480,17 @@ mod tests {
         let serialized = serde_json::to_string(&params).unwrap();
         assert_eq!(serialized, r#"{"metadata":{"foo":"bar"}}"#);
     }
+
+    #[test]
+    fn test_refund_list_params_encoding() {
+        let params = RefundListParams::new()
+            .payment_id(PaymentId::new("pay_123"))
+            .status(RefundStatus::Pending)
+            .created_at(RangeQuery::new().lte(Timestamp::from_unix(1_700_000_000)));
+        let encoded = serde_qs::to_string(&params).unwrap();
+
+        assert!(encoded.contains("payment_id=pay_123"));
+        assert!(encoded.contains("status=pending"));
+        assert!(encoded.contains("created_at[lte]=1700000000"));
+    }
 }
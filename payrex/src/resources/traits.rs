@@ -0,0 +1,59 @@
+//! Traits shared by resource clients.
+//!
+//! Resource clients reimplement `retrieve` and `list` with signatures tailored to each endpoint.
+//! [`Retrievable`] and [`Listable`] expose them uniformly so that generic code, such as exporters or
+//! mirrors, can work over any resource.
+
+use crate::{
+    Result,
+    types::{List, Resource},
+};
+use std::future::Future;
+
+/// A resource client whose resources can be retrieved by ID.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use payrex::{Client, resources::Retrievable, types::Resource};
+///
+/// async fn exists<R: Retrievable>(client: &R, id: &R::Id) -> bool {
+///     client.retrieve(id).await.is_ok()
+/// }
+/// ```
+pub trait Retrievable {
+    /// The ID type of the resource.
+    type Id;
+
+    /// The resource returned by the client.
+    type Item: Resource<Id = Self::Id>;
+
+    /// Retrieves a resource by ID.
+    fn retrieve(&self, id: &Self::Id) -> impl Future<Output = Result<Self::Item>> + Send;
+}
+
+/// A resource client whose resources can be listed.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use payrex::{Client, resources::Listable, types::Resource};
+///
+/// async fn first_page_ids<L: Listable>(client: &L) -> Result<Vec<String>, payrex::Error>
+/// where
+///     <L::Item as Resource>::Id: ToString,
+/// {
+///     let list = client.list(L::Params::default()).await?;
+///     Ok(list.iter().map(|item| item.id().to_string()).collect())
+/// }
+/// ```
+pub trait Listable {
+    /// The resource listed by the client.
+    type Item: Resource;
+
+    /// The query parameters accepted by the list endpoint.
+    type Params: Default + Send;
+
+    /// Lists resources matching the parameters.
+    fn list(&self, params: Self::Params) -> impl Future<Output = Result<List<Self::Item>>> + Send;
+}
@@ -5,11 +5,17 @@
 use crate::{
     Result,
     http::HttpClient,
-    types::{Deleted, List, ListParams, Timestamp, WebhookId, event::EventType},
+    resources::{Listable, Retrievable},
+    types::{
+        Deleted, List, ListParams, ListStream, ObjectType, RangeQuery, Resource, Timestamp,
+        WebhookId,
+        event::EventType,
+        pagination::{Paginate, paginate},
+    },
 };
 use payrex_derive::{Payrex, payrex_attr};
 use serde::{Deserialize, Serialize};
-use std::sync::Arc;
+use std::{future::Future, sync::Arc};
 
 /// Webhooks API
 #[derive(Clone)]
@@ -59,7 +65,7 @@ impl Webhooks {
     /// [API Reference](https://docs.payrexhq.com/docs/api/webhooks/delete)
     pub async fn delete(&self, id: &WebhookId) -> Result<Deleted<WebhookId>> {
         self.http
-            .delete(&format!("/webhooks/{}", id.as_str()))
+            .delete(&format!("/webhooks/{}", id.as_str()), id)
             .await
     }
 
@@ -72,6 +78,19 @@ impl Webhooks {
         self.http.get_with_params("/webhooks", &params).await
     }
 
+    /// Lists every webhook resource, fetching the next page lazily as the stream is consumed.
+    ///
+    /// Endpoint: `GET /webhooks`
+    ///
+    /// [API Reference](https://docs.payrexhq.com/docs/api/webhooks/list)
+    pub fn list_auto(&self, params: WebhookListParams) -> ListStream<Webhook> {
+        let http = Arc::clone(&self.http);
+        paginate(params, move |params: WebhookListParams| {
+            let http = Arc::clone(&http);
+            async move { http.get_with_params("/webhooks", &params).await }
+        })
+    }
+
     /// Enable a Webhook resource by ID.
     ///
     /// Endpoint: `POST /webhooks/:id/enable`
@@ -95,11 +114,29 @@ impl Webhooks {
     }
 }
 
+impl Retrievable for Webhooks {
+    type Id = WebhookId;
+    type Item = Webhook;
+
+    fn retrieve(&self, id: &Self::Id) -> impl Future<Output = Result<Self::Item>> + Send {
+        Self::retrieve(self, id)
+    }
+}
+
+impl Listable for Webhooks {
+    type Item = Webhook;
+    type Params = WebhookListParams;
+
+    fn list(&self, params: Self::Params) -> impl Future<Output = Result<List<Self::Item>>> + Send {
+        Self::list(self, params)
+    }
+}
+
 /// A Webhook resource is used to notify your application about events in your PayRex account.
 ///
 /// To learn more about webhooks, please refer to this
 /// [guide](https://docs.payrexhq.com/docs/guide/developer_handbook/webhooks).
-#[payrex_attr(livemode, timestamp, description = "webhook")]
+#[payrex_attr(resource, livemode, timestamp, description = "webhook")]
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Webhook {
     /// Unique identifier for the resource. The prefix is `wh_`.
@@ -125,8 +162,20 @@ pub struct Webhook {
     pub events: Vec<EventType>,
 }
 
+impl Resource for Webhook {
+    type Id = WebhookId;
+
+    fn id(&self) -> &Self::Id {
+        &self.id
+    }
+
+    fn object_type() -> ObjectType {
+        ObjectType::Webhook
+    }
+}
+
 /// The latest status of a Webhook.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum WebhookStatus {
     /// Webhook is enabled.
@@ -134,6 +183,10 @@ pub enum WebhookStatus {
 
     /// Webhook is disabled.
     Disabled,
+
+    /// A status not known to this version of the SDK, holding the original value.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 /// Query parameters when creating a webhook.
@@ -187,6 +240,19 @@ pub struct WebhookListParams {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[payrex(description = "Sets the URL in the query params when listing webhooks.")]
     pub url: Option<String>,
+
+    /// Filters webhooks by the time they were created, measured in seconds since the Unix epoch.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[payrex(
+        description = "Sets the creation time range in the query params when listing webhooks."
+    )]
+    pub created_at: Option<RangeQuery<Timestamp>>,
+}
+
+impl Paginate for WebhookListParams {
+    fn list_params_mut(&mut self) -> &mut ListParams {
+        &mut self.list_params
+    }
 }
 
 #[cfg(test)]
@@ -210,6 +276,7 @@ mod tests {
     #[test]
     fn test_webhook_serialization() {
         let webhook = Webhook {
+            resource: ObjectType::Webhook,
             id: WebhookId::new("wh_123"),
             secret_key: Some("secret".to_string()),
             status: WebhookStatus::Enabled,
@@ -219,6 +286,7 @@ mod tests {
             events: vec![EventType::CheckoutSession(CheckoutSessionEvent::Expired)],
             created_at: Timestamp::from_unix(1_600_000),
             updated_at: Timestamp::from_unix(1_600_001),
+            extra: serde_json::Map::new(),
         };
 
         let json = serde_json::to_value(&webhook).unwrap();
@@ -1,6 +1,7 @@
 //! Common types and traits used across the SDK.
 
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, de::Error as _};
+use std::fmt::Display;
 
 /// Represents a PayRex resource.
 pub trait Resource {
@@ -10,12 +11,12 @@ pub trait Resource {
     /// Returns the ID of the resource.
     fn id(&self) -> &Self::Id;
 
-    /// Returns the string slice representation of the object type.
-    fn object_type() -> &'static str;
+    /// Returns the object type of the resource, as found in the `resource` field of its payload.
+    fn object_type() -> ObjectType;
 }
 
 /// Types of objects from resources received from endpoints in PayRex.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum ObjectType {
     /// Payment intent resource object
@@ -77,6 +78,51 @@ pub enum ObjectType {
     List,
 }
 
+impl ObjectType {
+    /// Returns the string representation of the object type.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::PaymentIntent => "payment_intent",
+            Self::Customer => "customer",
+            Self::BillingStatement => "billing_statement",
+            Self::BillingStatementLineItem => "billing_statement_line_item",
+            Self::CheckoutSession => "checkout_session",
+            Self::Payment => "payment",
+            Self::Refund => "refund",
+            Self::Webhook => "webhook",
+            Self::Event => "event",
+            Self::Payout => "payout",
+            Self::PayoutTransaction => "payout_transaction",
+            Self::List => "list",
+        }
+    }
+}
+
+impl Display for ObjectType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Deserializes the `resource` field of a payload, failing if it is not the object type of `T`.
+pub(crate) fn deserialize_object_type<'de, T, D>(deserializer: D) -> Result<ObjectType, D::Error>
+where
+    T: Resource,
+    D: Deserializer<'de>,
+{
+    let object_type = ObjectType::deserialize(deserializer)?;
+    let expected = T::object_type();
+
+    if object_type != expected {
+        return Err(D::Error::custom(format!(
+            "expected a `{expected}` resource, got `{object_type}`"
+        )));
+    }
+
+    Ok(object_type)
+}
+
 /// This represents a deleted resource from a delete endpoint in PayRex.
 ///
 /// The ID represents the resource ID of a deleted resource.
@@ -85,7 +131,9 @@ pub struct Deleted<Id> {
     /// The Resource ID of a deleted resource.
     pub id: Id,
 
-    /// Returns `true` if a resource was deleted successfully.
+    /// Returns `true` if a resource was deleted successfully. Responses that omit the flag are
+    /// treated as successful deletions.
+    #[serde(default = "deleted_default")]
     pub deleted: bool,
 
     /// Contains the actual object/data in the resource.
@@ -93,6 +141,10 @@ pub struct Deleted<Id> {
     pub object: Option<String>,
 }
 
+const fn deleted_default() -> bool {
+    true
+}
+
 impl<Id> Deleted<Id> {
     /// Creates a new [`Deleted`] instance for a resource.
     #[must_use]
@@ -153,6 +205,51 @@ impl<Id, T> Expandable<Id, T> {
             Self::Object(obj) => Some(obj),
         }
     }
+
+    /// Consumes the [`Expandable`], returning `Some(T)` if it is an object.
+    #[must_use]
+    pub fn into_object(self) -> Option<T> {
+        match self {
+            Self::Id(_) => None,
+            Self::Object(obj) => Some(*obj),
+        }
+    }
+}
+
+impl<Id, T: Resource<Id = Id>> Expandable<Id, T> {
+    /// Returns the ID of the resource, whether or not it was expanded.
+    #[must_use]
+    pub fn id(&self) -> &Id {
+        match self {
+            Self::Id(id) => id,
+            Self::Object(obj) => obj.id(),
+        }
+    }
+}
+
+/// Parameters for retrieving a resource with related resources expanded inline.
+///
+/// Expanded fields are returned as an [`Expandable::Object`] instead of an [`Expandable::Id`].
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ExpandParams {
+    /// The names of the fields to expand, e.g. `customer` or `payment_intent_id`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub expand: Vec<String>,
+}
+
+impl ExpandParams {
+    /// Creates a new [`ExpandParams`] instance without any expanded field.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { expand: Vec::new() }
+    }
+
+    /// Adds a field to expand in the response.
+    #[must_use]
+    pub fn expand(mut self, field: impl Into<String>) -> Self {
+        self.expand.push(field.into());
+        self
+    }
 }
 
 /// Represents the valid range of a query in list parameters.
@@ -244,6 +341,72 @@ mod tests {
         assert_eq!(expandable.as_object(), Some(&"test_object".to_string()));
     }
 
+    #[test]
+    fn test_object_type_display() {
+        assert_eq!(ObjectType::PaymentIntent.to_string(), "payment_intent");
+        assert_eq!(
+            ObjectType::PayoutTransaction.to_string(),
+            "payout_transaction"
+        );
+    }
+
+    #[test]
+    fn test_object_type_as_str_matches_serde() {
+        for object_type in [
+            ObjectType::PaymentIntent,
+            ObjectType::Customer,
+            ObjectType::BillingStatement,
+            ObjectType::BillingStatementLineItem,
+            ObjectType::CheckoutSession,
+            ObjectType::Payment,
+            ObjectType::Refund,
+            ObjectType::Webhook,
+            ObjectType::Event,
+            ObjectType::Payout,
+            ObjectType::PayoutTransaction,
+            ObjectType::List,
+        ] {
+            assert_eq!(
+                serde_plain::to_string(&object_type).unwrap(),
+                object_type.as_str()
+            );
+        }
+    }
+
+    #[test]
+    fn test_expandable_deserialization() {
+        use crate::resources::payments::Payment;
+        use crate::types::PaymentId;
+
+        let expandable: Expandable<PaymentId, Payment> =
+            serde_json::from_str("\"pay_123\"").unwrap();
+        assert_eq!(expandable.id().as_str(), "pay_123");
+        assert!(expandable.into_object().is_none());
+    }
+
+    #[test]
+    fn test_deleted_deserialization() {
+        use crate::types::CustomerId;
+
+        let deleted: Deleted<CustomerId> =
+            serde_json::from_str(r#"{"id":"cus_123","deleted":false}"#).unwrap();
+        assert!(!deleted.deleted);
+
+        let deleted: Deleted<CustomerId> = serde_json::from_str(r#"{"id":"cus_123"}"#).unwrap();
+        assert_eq!(deleted, Deleted::new(CustomerId::new("cus_123")));
+    }
+
+    #[test]
+    fn test_expand_params() {
+        let params = ExpandParams::new()
+            .expand("customer")
+            .expand("payment_intent_id");
+        assert_eq!(params.expand, vec!["customer", "payment_intent_id"]);
+
+        let json = serde_json::to_value(ExpandParams::new()).unwrap();
+        assert!(json.get("expand").is_none());
+    }
+
     #[test]
     fn test_range_query() {
         let range = RangeQuery::new().gte(10).lt(100);
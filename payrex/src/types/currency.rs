@@ -5,44 +5,184 @@
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
+/// How the currency is shown by [`Currency::format_amount_with`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum CurrencyDisplay {
+    /// Shows the currency symbol, e.g. `₱1,234.50`.
+    #[default]
+    Symbol,
+
+    /// Shows the ISO code, e.g. `PHP 1,234.50`.
+    Code,
+
+    /// Shows the number only, e.g. `1,234.50`.
+    Hidden,
+}
+
+/// How negative amounts are shown by [`Currency::format_amount_with`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum NegativeStyle {
+    /// Prefixes negative amounts with a minus sign, e.g. `-₱1,234.50`.
+    #[default]
+    Minus,
+
+    /// Wraps negative amounts in parentheses as in accounting, e.g. `(₱1,234.50)`.
+    Parentheses,
+}
+
+/// Options of [`Currency::format_amount_with`].
+///
+/// The default options show the currency symbol without thousands separators, matching
+/// [`Currency::format_amount`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct FormatOptions {
+    grouping: bool,
+    currency_display: CurrencyDisplay,
+    negative_style: NegativeStyle,
+    positive_sign: bool,
+}
+
+impl FormatOptions {
+    /// Creates the default [`FormatOptions`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets whether the whole number part is split into groups of three digits, e.g. `1,234.50`.
+    #[must_use]
+    pub const fn grouping(mut self, grouping: bool) -> Self {
+        self.grouping = grouping;
+        self
+    }
+
+    /// Sets how the currency is shown.
+    #[must_use]
+    pub const fn currency_display(mut self, currency_display: CurrencyDisplay) -> Self {
+        self.currency_display = currency_display;
+        self
+    }
+
+    /// Sets how negative amounts are shown.
+    #[must_use]
+    pub const fn negative_style(mut self, negative_style: NegativeStyle) -> Self {
+        self.negative_style = negative_style;
+        self
+    }
+
+    /// Sets whether positive amounts are prefixed with a plus sign, e.g. `+₱1,234.50`. Zero is
+    /// never signed.
+    #[must_use]
+    pub const fn positive_sign(mut self, positive_sign: bool) -> Self {
+        self.positive_sign = positive_sign;
+        self
+    }
+}
+
+/// Errors from parsing a human-formatted amount with [`Currency::parse_amount`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ParseAmountError {
+    /// The input has no amount.
+    #[error("amount is empty")]
+    Empty,
+
+    /// The input is missing digits before or after the decimal point, e.g. `.50` or `12.`.
+    #[error("amount is missing digits around the decimal point")]
+    MissingDigits,
+
+    /// The input is negative, while amounts sent to PayRex are never negative.
+    #[error("amount must not be negative")]
+    Negative,
+
+    /// The amount is parsed for a [`Currency::Unknown`], whose number of decimal places is not
+    /// known to this version of the SDK.
+    #[error("cannot parse amounts of unknown currency `{0}`")]
+    UnknownCurrency(String),
+
+    /// The input names a currency other than the one it is parsed for.
+    #[error("unexpected currency `{0}`")]
+    UnexpectedCurrency(String),
+
+    /// The input contains a character that is not part of an amount.
+    #[error("invalid character `{0}` in amount")]
+    InvalidCharacter(char),
+
+    /// The thousands separators do not split the whole number part into groups of three digits.
+    #[error("thousands separators must split the amount into groups of three digits")]
+    InvalidGrouping,
+
+    /// The input has more decimal places than the currency allows.
+    #[error("amount has more than {0} decimal places")]
+    TooPrecise(u8),
+
+    /// The amount does not fit in the smallest currency unit.
+    #[error("amount is too large")]
+    Overflow,
+}
+
 /// Currency codes supported by PayRex.
 ///
 /// **Note**: PayRex currently only supports PHP (Philippine Peso).
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "UPPERCASE")]
 pub enum Currency {
     /// Philippine Peso (PHP)
     #[default]
     PHP,
+
+    /// A currency not known to this version of the SDK, holding the original code.
+    ///
+    /// **Warning**: the SDK does not know how many decimal places such a currency has. Parsing
+    /// amounts ([`Currency::parse_amount`], [`Money::parse`](crate::types::Money::parse)) and
+    /// converting from major units ([`Money::from_major`](crate::types::Money::from_major)) fail
+    /// with a typed error, but the infallible [`Currency::decimal_places`], formatting and
+    /// major/minor accessors assume two decimal places, which is wrong for currencies such as JPY.
+    /// Only the amount in the smallest currency unit is reliable.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl Currency {
     /// Returns the string slice representation (in uppercase) for a [`Currency`].
     #[must_use]
-    pub const fn as_str(self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             Self::PHP => "PHP",
+            Self::Unknown(code) => code,
         }
     }
 
-    /// Returns the Unicode symbol for a [`Currency`].
+    /// Returns the Unicode symbol for a [`Currency`]. Unknown currencies use their code instead.
     #[must_use]
-    pub const fn symbol(self) -> &'static str {
+    pub fn symbol(&self) -> &str {
         match self {
             Self::PHP => "₱",
+            Self::Unknown(code) => code,
         }
     }
 
     /// Returns the number of decimal places for a specified currency.
+    ///
+    /// **Warning**: [`Currency::Unknown`] currencies are assumed to have two decimal places, which
+    /// may be wrong. Use [`Currency::is_known`] to detect them.
     #[must_use]
-    pub const fn decimal_places(self) -> u8 {
+    pub const fn decimal_places(&self) -> u8 {
         match self {
-            Self::PHP => 2,
+            Self::PHP | Self::Unknown(_) => 2,
         }
     }
 
+    /// Returns `false` for a [`Currency::Unknown`], whose number of decimal places is a guess.
+    #[must_use]
+    pub const fn is_known(&self) -> bool {
+        !matches!(self, Self::Unknown(_))
+    }
+
     /// Format an amount in the smallest currency unit (e.g., centavos for PHP).
     ///
+    /// **Warning**: [`Currency::Unknown`] amounts are formatted with two decimal places, which may
+    /// be wrong. See [`Currency::decimal_places`].
+    ///
     /// # Examples
     ///
     /// ```
@@ -52,7 +192,7 @@ impl Currency {
     /// assert_eq!(formatted, "₱100.50");
     /// ```
     #[must_use]
-    pub fn format_amount(self, amount: u64) -> String {
+    pub fn format_amount(&self, amount: u64) -> String {
         let decimal_places = self.decimal_places();
         let divisor = 10_u64.pow(u32::from(decimal_places));
         let major = amount / divisor;
@@ -66,6 +206,210 @@ impl Currency {
             width = decimal_places as usize
         )
     }
+
+    /// Formats a signed amount in the smallest currency unit according to the
+    /// [`FormatOptions`], e.g. for receipts and reports.
+    ///
+    /// **Warning**: [`Currency::Unknown`] amounts are formatted with two decimal places, which may
+    /// be wrong. See [`Currency::decimal_places`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use payrex::types::{Currency, CurrencyDisplay, FormatOptions, NegativeStyle};
+    ///
+    /// let options = FormatOptions::new().grouping(true);
+    /// assert_eq!(Currency::PHP.format_amount_with(123_450, options), "₱1,234.50");
+    ///
+    /// let options = options
+    ///     .currency_display(CurrencyDisplay::Code)
+    ///     .negative_style(NegativeStyle::Parentheses);
+    /// assert_eq!(Currency::PHP.format_amount_with(-123_450, options), "(PHP 1,234.50)");
+    /// ```
+    #[must_use]
+    pub fn format_amount_with(&self, amount: i64, options: FormatOptions) -> String {
+        let decimal_places = usize::from(self.decimal_places());
+        let divisor = 10_u64.pow(u32::from(self.decimal_places()));
+        let magnitude = amount.unsigned_abs();
+        let major = (magnitude / divisor).to_string();
+        let minor = magnitude % divisor;
+
+        let major = if options.grouping {
+            Self::group_thousands(&major)
+        } else {
+            major
+        };
+        let number = if decimal_places == 0 {
+            major
+        } else {
+            format!("{major}.{minor:0decimal_places$}")
+        };
+
+        let body = match options.currency_display {
+            CurrencyDisplay::Symbol => format!("{}{number}", self.symbol()),
+            CurrencyDisplay::Code => format!("{} {number}", self.as_str()),
+            CurrencyDisplay::Hidden => number,
+        };
+
+        match (amount.signum(), options.negative_style) {
+            (-1, NegativeStyle::Minus) => format!("-{body}"),
+            (-1, NegativeStyle::Parentheses) => format!("({body})"),
+            (1, _) if options.positive_sign => format!("+{body}"),
+            _ => body,
+        }
+    }
+
+    /// Inserts a `,` between every group of three digits, counting from the right.
+    fn group_thousands(digits: &str) -> String {
+        let mut grouped = String::with_capacity(digits.len() + digits.len() / 3);
+        for (i, digit) in digits.chars().enumerate() {
+            if i > 0 && (digits.len() - i).is_multiple_of(3) {
+                grouped.push(',');
+            }
+            grouped.push(digit);
+        }
+        grouped
+    }
+}
+
+impl Currency {
+    /// Parses a human-formatted amount into the smallest currency unit, the inverse of
+    /// [`Currency::format_amount`].
+    ///
+    /// The amount may be preceded by the currency symbol or ISO code, or followed by the ISO code,
+    /// and may use `,` as a thousands separator and `.` as the decimal point. Parsing is strict:
+    /// misplaced separators, other currencies, and more decimal places than
+    /// [`Currency::decimal_places`] are rejected rather than guessed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use payrex::types::Currency;
+    ///
+    /// assert_eq!(Currency::PHP.parse_amount("₱1,234.50"), Ok(123_450));
+    /// assert_eq!(Currency::PHP.parse_amount("1234.5"), Ok(123_450));
+    /// assert_eq!(Currency::PHP.parse_amount("PHP 1234.50"), Ok(123_450));
+    /// assert!(Currency::PHP.parse_amount("1.234,50").is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseAmountError`] describing why the input is not a valid amount, and
+    /// [`ParseAmountError::UnknownCurrency`] for a [`Currency::Unknown`].
+    pub fn parse_amount(&self, input: &str) -> Result<u64, ParseAmountError> {
+        if let Self::Unknown(code) = self {
+            return Err(ParseAmountError::UnknownCurrency(code.clone()));
+        }
+        let number = self.strip_currency(input.trim())?;
+
+        if number.is_empty() {
+            return Err(ParseAmountError::Empty);
+        }
+        if number.starts_with('-') {
+            return Err(ParseAmountError::Negative);
+        }
+
+        let (whole, fraction) = match number.split_once('.') {
+            Some((whole, fraction)) => (whole, Some(fraction)),
+            None => (number, None),
+        };
+
+        let whole = Self::parse_whole(whole)?;
+        let fraction = match fraction {
+            Some(fraction) => self.parse_fraction(fraction)?,
+            None => 0,
+        };
+
+        10_u64
+            .checked_pow(u32::from(self.decimal_places()))
+            .and_then(|divisor| whole.checked_mul(divisor))
+            .and_then(|minor| minor.checked_add(fraction))
+            .ok_or(ParseAmountError::Overflow)
+    }
+
+    /// Removes the currency symbol or ISO code around the amount.
+    fn strip_currency<'a>(&self, input: &'a str) -> Result<&'a str, ParseAmountError> {
+        let code_len = self.as_str().len();
+
+        let stripped = if let Some(rest) = input.strip_prefix(self.symbol()) {
+            rest
+        } else if input
+            .get(..code_len)
+            .is_some_and(|code| code.eq_ignore_ascii_case(self.as_str()))
+        {
+            &input[code_len..]
+        } else if input.len() >= code_len
+            && input
+                .get(input.len() - code_len..)
+                .is_some_and(|code| code.eq_ignore_ascii_case(self.as_str()))
+        {
+            &input[..input.len() - code_len]
+        } else {
+            input
+        };
+
+        let stripped = stripped.trim();
+        let code: String = stripped
+            .chars()
+            .take_while(char::is_ascii_alphabetic)
+            .collect();
+        if !code.is_empty() {
+            return Err(ParseAmountError::UnexpectedCurrency(code));
+        }
+
+        Ok(stripped)
+    }
+
+    /// Parses the whole number part, validating its thousands separators.
+    fn parse_whole(whole: &str) -> Result<u64, ParseAmountError> {
+        if whole.is_empty() {
+            return Err(ParseAmountError::MissingDigits);
+        }
+
+        let mut groups = whole.split(',');
+        let first = groups.next().unwrap_or_default();
+        let grouped = whole.contains(',');
+
+        if grouped && (first.is_empty() || first.len() > 3) {
+            return Err(ParseAmountError::InvalidGrouping);
+        }
+
+        let mut digits = String::with_capacity(whole.len());
+        digits.push_str(first);
+        for group in groups {
+            if group.len() != 3 {
+                return Err(ParseAmountError::InvalidGrouping);
+            }
+            digits.push_str(group);
+        }
+
+        Self::parse_digits(&digits)
+    }
+
+    /// Parses the fractional part, padded to the decimal places of the currency.
+    fn parse_fraction(&self, fraction: &str) -> Result<u64, ParseAmountError> {
+        let decimal_places = usize::from(self.decimal_places());
+
+        if fraction.is_empty() {
+            return Err(ParseAmountError::MissingDigits);
+        }
+        if fraction.len() > decimal_places {
+            return match fraction.chars().find(|c| !c.is_ascii_digit()) {
+                Some(c) => Err(ParseAmountError::InvalidCharacter(c)),
+                None => Err(ParseAmountError::TooPrecise(self.decimal_places())),
+            };
+        }
+
+        Self::parse_digits(&format!("{fraction:0<decimal_places$}"))
+    }
+
+    fn parse_digits(digits: &str) -> Result<u64, ParseAmountError> {
+        if let Some(c) = digits.chars().find(|c| !c.is_ascii_digit()) {
+            return Err(ParseAmountError::InvalidCharacter(c));
+        }
+
+        digits.parse().map_err(|_| ParseAmountError::Overflow)
+    }
 }
 
 impl fmt::Display for Currency {
@@ -91,6 +435,16 @@ mod tests {
     #[test]
     fn test_currency_decimal_places() {
         assert_eq!(Currency::PHP.decimal_places(), 2);
+        assert!(Currency::PHP.is_known());
+        assert!(!Currency::Unknown("JPY".to_string()).is_known());
+    }
+
+    #[test]
+    fn test_parse_amount_unknown_currency() {
+        assert_eq!(
+            Currency::Unknown("JPY".to_string()).parse_amount("1000"),
+            Err(ParseAmountError::UnknownCurrency("JPY".to_string()))
+        );
     }
 
     #[test]
@@ -100,10 +454,102 @@ mod tests {
         assert_eq!(Currency::PHP.format_amount(0), "₱0.00");
     }
 
-    //#[test]
-    //fn test_format_amount_negative() {
-    //    assert_eq!(Currency::PHP.format_amount(-10050), "₱-100.50");
-    //}
+    #[test]
+    fn test_format_amount_negative() {
+        let options = FormatOptions::new();
+        assert_eq!(
+            Currency::PHP.format_amount_with(-10050, options),
+            "-₱100.50"
+        );
+
+        let options = options.negative_style(NegativeStyle::Parentheses);
+        assert_eq!(
+            Currency::PHP.format_amount_with(-10050, options),
+            "(₱100.50)"
+        );
+        assert_eq!(Currency::PHP.format_amount_with(10050, options), "₱100.50");
+    }
+
+    #[test]
+    fn test_format_amount_with_options() {
+        let php = Currency::PHP;
+        let grouped = FormatOptions::new().grouping(true);
+
+        assert_eq!(
+            php.format_amount_with(10050, FormatOptions::new()),
+            php.format_amount(10050)
+        );
+        assert_eq!(php.format_amount_with(0, grouped), "₱0.00");
+        assert_eq!(php.format_amount_with(99_999, grouped), "₱999.99");
+        assert_eq!(php.format_amount_with(123_450, grouped), "₱1,234.50");
+        assert_eq!(
+            php.format_amount_with(123_456_789, grouped),
+            "₱1,234,567.89"
+        );
+        assert_eq!(
+            php.format_amount_with(i64::MIN, grouped),
+            "-₱92,233,720,368,547,758.08"
+        );
+
+        let code = grouped.currency_display(CurrencyDisplay::Code);
+        assert_eq!(php.format_amount_with(123_450, code), "PHP 1,234.50");
+
+        let hidden = grouped.currency_display(CurrencyDisplay::Hidden);
+        assert_eq!(php.format_amount_with(-123_450, hidden), "-1,234.50");
+
+        let signed = grouped.positive_sign(true);
+        assert_eq!(php.format_amount_with(123_450, signed), "+₱1,234.50");
+        assert_eq!(php.format_amount_with(0, signed), "₱0.00");
+    }
+
+    #[test]
+    fn test_format_amount_with_round_trips_through_parse() {
+        let php = Currency::PHP;
+        let options = FormatOptions::new().grouping(true);
+
+        for amount in [0, 5, 100_000, 123_456_789] {
+            let formatted = php.format_amount_with(amount, options);
+            assert_eq!(php.parse_amount(&formatted), Ok(amount.unsigned_abs()));
+        }
+    }
+
+    #[test]
+    fn test_parse_amount() {
+        let php = Currency::PHP;
+
+        assert_eq!(php.parse_amount("₱1,234.50"), Ok(123_450));
+        assert_eq!(php.parse_amount("1234.5"), Ok(123_450));
+        assert_eq!(php.parse_amount("PHP 1234.50"), Ok(123_450));
+        assert_eq!(php.parse_amount("php1234"), Ok(123_400));
+        assert_eq!(php.parse_amount("1,234,567.89 PHP"), Ok(123_456_789));
+        assert_eq!(php.parse_amount(" ₱ 0.05 "), Ok(5));
+        assert_eq!(php.parse_amount(&php.format_amount(10_050)), Ok(10_050));
+    }
+
+    #[test]
+    fn test_parse_amount_rejects_ambiguous_input() {
+        use ParseAmountError::*;
+        let php = Currency::PHP;
+
+        assert_eq!(php.parse_amount(""), Err(Empty));
+        assert_eq!(php.parse_amount("₱"), Err(Empty));
+        assert_eq!(php.parse_amount("-10.00"), Err(Negative));
+        assert_eq!(php.parse_amount("1.234,50"), Err(InvalidCharacter(',')));
+        assert_eq!(php.parse_amount("12,34.50"), Err(InvalidGrouping));
+        assert_eq!(php.parse_amount("1234,567"), Err(InvalidGrouping));
+        assert_eq!(php.parse_amount(",123"), Err(InvalidGrouping));
+        assert_eq!(php.parse_amount("1.234.50"), Err(InvalidCharacter('.')));
+        assert_eq!(php.parse_amount("10.505"), Err(TooPrecise(2)));
+        assert_eq!(php.parse_amount(".50"), Err(MissingDigits));
+        assert_eq!(php.parse_amount("12."), Err(MissingDigits));
+        assert_eq!(
+            php.parse_amount("USD 12.00"),
+            Err(UnexpectedCurrency("USD".to_string()))
+        );
+        assert_eq!(php.parse_amount("$12.00"), Err(InvalidCharacter('$')));
+        assert_eq!(php.parse_amount("12 000"), Err(InvalidCharacter(' ')));
+        assert_eq!(php.parse_amount("184467440737095516.16"), Err(Overflow));
+    }
 
     #[test]
     fn test_currency_serialization() {
@@ -119,6 +565,16 @@ mod tests {
         assert_eq!(currency, Currency::PHP);
     }
 
+    #[test]
+    fn test_currency_unknown_round_trip() {
+        let currency: Currency = serde_json::from_str("\"USD\"").unwrap();
+        assert_eq!(currency, Currency::Unknown("USD".to_string()));
+        assert_eq!(currency.as_str(), "USD");
+        assert_eq!(currency.symbol(), "USD");
+        assert_eq!(currency.decimal_places(), 2);
+        assert_eq!(serde_json::to_string(&currency).unwrap(), "\"USD\"");
+    }
+
     #[test]
     fn test_currency_default() {
         assert_eq!(Currency::default(), Currency::PHP);
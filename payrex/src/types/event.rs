@@ -6,7 +6,7 @@ use payrex_derive::payrex_attr;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use serde_json::Value;
 
-use crate::types::{EventId, Timestamp};
+use crate::types::{EventId, ObjectType, Resource, Timestamp};
 
 /// An Event resource represents updates in your PayRex account triggered either by API calls or
 /// your actions from the Dashboard. When an event occurs, for example, a successfully paid payment
@@ -16,7 +16,7 @@ use crate::types::{EventId, Timestamp};
 /// resource's data attribute contains the resource's state or snapshot at the time of the change.
 /// For example, a payment_intent.succeeded event contains a Payment Intent, and a payment.refunded
 /// event contains a Payment resource.
-#[payrex_attr(livemode, timestamp)]
+#[payrex_attr(resource, livemode, timestamp)]
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Event {
     /// Unique identifier for the resource. The prefix is `evt_`.
@@ -39,6 +39,18 @@ pub struct Event {
     //pub previous_attributes: Option<Value>,
 }
 
+impl Resource for Event {
+    type Id = EventId;
+
+    fn id(&self) -> &Self::Id {
+        &self.id
+    }
+
+    fn object_type() -> ObjectType {
+        ObjectType::Event
+    }
+}
+
 /// The event types follow a pattern: `<resource>.<event>`. We aim to be consistent, making things
 /// easier and more organized.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -60,6 +72,9 @@ pub enum EventType {
 
     /// Event types about Refund.
     Refund(RefundEvent),
+
+    /// An event type not known to this version of the SDK, holding the original value.
+    Unknown(String),
 }
 
 /// Event types about Billing Statement.
@@ -199,17 +214,19 @@ impl Serialize for EventType {
     where
         S: Serializer,
     {
-        let s = match self {
-            EventType::BillingStatement(e) => format!("billing_statement.{e:?}"),
+        let (prefix, event) = match self {
+            EventType::BillingStatement(e) => ("billing_statement", serde_plain::to_string(e)),
             EventType::BillingStatementLineItem(e) => {
-                format!("billing_statement_line_item.{e:?}")
+                ("billing_statement_line_item", serde_plain::to_string(e))
             }
-            EventType::CheckoutSession(e) => format!("checkout_session.{e:?}"),
-            EventType::PaymentIntent(e) => format!("payment_intent.{e:?}"),
-            EventType::Payout(e) => format!("payout.{e:?}"),
-            EventType::Refund(e) => format!("refund.{e:?}"),
+            EventType::CheckoutSession(e) => ("checkout_session", serde_plain::to_string(e)),
+            EventType::PaymentIntent(e) => ("payment_intent", serde_plain::to_string(e)),
+            EventType::Payout(e) => ("payout", serde_plain::to_string(e)),
+            EventType::Refund(e) => ("refund", serde_plain::to_string(e)),
+            EventType::Unknown(s) => return serializer.serialize_str(s),
         };
-        serializer.serialize_str(&s.to_lowercase())
+        let event = event.map_err(serde::ser::Error::custom)?;
+        serializer.serialize_str(&format!("{prefix}.{event}"))
     }
 }
 
@@ -219,37 +236,27 @@ impl<'de> Deserialize<'de> for EventType {
         D: Deserializer<'de>,
     {
         let s = String::deserialize(deserializer)?;
-        let parts: Vec<&str> = s.split('.').collect();
-        if parts.len() != 2 {
-            return Err(serde::de::Error::custom("invalid event format"));
-        }
+        Ok(EventType::parse_known(&s).unwrap_or(EventType::Unknown(s)))
+    }
+}
 
-        let (prefix, event) = (parts[0], parts[1]);
-        Ok(match prefix {
-            "billing_statement" => EventType::BillingStatement(
-                serde_plain::from_str(event).map_err(serde::de::Error::custom)?,
-            ),
-            "billing_statement_line_item" => EventType::BillingStatementLineItem(
-                serde_plain::from_str(event).map_err(serde::de::Error::custom)?,
-            ),
-            "checkout_session" => EventType::CheckoutSession(
-                serde_plain::from_str(event).map_err(serde::de::Error::custom)?,
-            ),
-            "payment_intent" => EventType::PaymentIntent(
-                serde_plain::from_str(event).map_err(serde::de::Error::custom)?,
-            ),
-            "payout" => {
-                EventType::Payout(serde_plain::from_str(event).map_err(serde::de::Error::custom)?)
-            }
-            "refund" => {
-                EventType::Refund(serde_plain::from_str(event).map_err(serde::de::Error::custom)?)
+impl EventType {
+    /// Parses an event type known to this version of the SDK, returning `None` otherwise.
+    fn parse_known(s: &str) -> Option<Self> {
+        let (prefix, event) = s.split_once('.')?;
+        Some(match prefix {
+            "billing_statement" => EventType::BillingStatement(serde_plain::from_str(event).ok()?),
+            "billing_statement_line_item" => {
+                EventType::BillingStatementLineItem(serde_plain::from_str(event).ok()?)
             }
-            _ => return Err(serde::de::Error::custom("unknown event type")),
+            "checkout_session" => EventType::CheckoutSession(serde_plain::from_str(event).ok()?),
+            "payment_intent" => EventType::PaymentIntent(serde_plain::from_str(event).ok()?),
+            "payout" => EventType::Payout(serde_plain::from_str(event).ok()?),
+            "refund" => EventType::Refund(serde_plain::from_str(event).ok()?),
+            _ => return None,
         })
     }
-}
 
-impl EventType {
     /// Returns the String representation of an event type.
     #[must_use]
     pub fn as_str(&self) -> String {
@@ -283,6 +290,23 @@ mod tests {
         let et2 = EventType::Refund(RefundEvent::Updated);
         assert_eq!(et2.as_str(), "refund.updated");
         assert_eq!(serde_json::to_string(&et2).unwrap(), "\"refund.updated\"");
+
+        let et3 = EventType::BillingStatement(BillingStatementEvent::MarkedUncollectible);
+        assert_eq!(et3.as_str(), "billing_statement.marked_uncollectible");
+        assert_eq!(
+            serde_json::from_str::<EventType>("\"billing_statement.marked_uncollectible\"")
+                .unwrap(),
+            et3
+        );
+    }
+
+    #[test]
+    fn test_event_type_unknown_round_trip() {
+        for value in ["payment_intent.expired", "dispute.created", "invalid"] {
+            let et: EventType = serde_json::from_value(json!(value)).unwrap();
+            assert_eq!(et, EventType::Unknown(value.to_string()));
+            assert_eq!(serde_json::to_value(&et).unwrap(), json!(value));
+        }
     }
 
     #[test]
@@ -290,6 +314,7 @@ mod tests {
         let id = EventId::new("evt_123");
         let data = json!({"key": "value"});
         let event = Event {
+            resource: ObjectType::Event,
             id: id.clone(),
             data: data.clone(),
             event_type: EventType::CheckoutSession(CheckoutSessionEvent::Expired),
@@ -297,6 +322,7 @@ mod tests {
             livemode: false,
             created_at: Timestamp::from_unix(1_600_000_000),
             updated_at: Timestamp::from_unix(1_600_000_500),
+            extra: serde_json::Map::new(),
         };
 
         let json = serde_json::to_value(&event).unwrap();
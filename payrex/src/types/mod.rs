@@ -8,15 +8,17 @@ pub mod currency;
 pub mod event;
 pub mod ids;
 pub mod metadata;
+pub mod money;
 pub mod pagination;
 pub mod payment_methods;
 pub mod timestamp;
 
 // Re-export commonly used types
 pub use common::*;
-pub use currency::Currency;
+pub use currency::{Currency, CurrencyDisplay, FormatOptions, NegativeStyle, ParseAmountError};
 pub use ids::*;
 pub use metadata::Metadata;
-pub use pagination::{List, ListParams};
+pub use money::Money;
+pub use pagination::{List, ListParams, ListStream};
 pub use payment_methods::*;
 pub use timestamp::Timestamp;
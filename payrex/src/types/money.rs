@@ -0,0 +1,387 @@
+//! Monetary amounts for the PayRex SDK.
+//!
+//! PayRex represents amounts as integers in the smallest currency unit, e.g. centavos for PHP.
+//! [`Money`] pairs such an amount with its [`Currency`] and only allows arithmetic that cannot
+//! silently overflow or mix currencies.
+
+use crate::{
+    Error, Result,
+    types::{Currency, FormatOptions},
+};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// An amount in the smallest currency unit together with its [`Currency`].
+///
+/// The amount is signed so that debits, such as adjustments in a payout, can be represented.
+///
+/// **Warning**: for a [`Currency::Unknown`], only the amount in the smallest currency unit is
+/// reliable. [`Money::from_major`] and [`Money::parse`] fail, while [`Money::major`],
+/// [`Money::minor`] and formatting assume two decimal places, which may be wrong.
+///
+/// # Examples
+///
+/// ```
+/// use payrex::types::{Currency, Money};
+///
+/// # fn main() -> Result<(), payrex::Error> {
+/// let unit_price = Money::new(12_050, Currency::PHP);
+/// let total = unit_price.checked_mul(3)?;
+/// assert_eq!(total.amount(), 36_150);
+///
+/// let shares = total.allocate(&[70, 30])?;
+/// assert_eq!(shares[0].amount() + shares[1].amount(), 36_150);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Money {
+    amount: i64,
+    currency: Currency,
+}
+
+impl Money {
+    /// Creates a new [`Money`] from an amount in the smallest currency unit.
+    #[must_use]
+    pub const fn new(amount: i64, currency: Currency) -> Self {
+        Self { amount, currency }
+    }
+
+    /// Creates a zero amount in the currency.
+    #[must_use]
+    pub const fn zero(currency: Currency) -> Self {
+        Self::new(0, currency)
+    }
+
+    /// Creates a [`Money`] from an unsigned amount in the smallest currency unit, such as the
+    /// amounts returned by PayRex.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::AmountOverflow`] if the amount does not fit in an `i64`.
+    pub fn from_minor(amount: u64, currency: Currency) -> Result<Self> {
+        let amount = i64::try_from(amount).map_err(|_| Error::AmountOverflow)?;
+        Ok(Self::new(amount, currency))
+    }
+
+    /// Creates a [`Money`] from a whole amount in major units, e.g. pesos for PHP.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::UnknownCurrency`] for a [`Currency::Unknown`], and
+    /// [`Error::AmountOverflow`] if the amount in the smallest currency unit does not fit in an
+    /// `i64`.
+    pub fn from_major(major: i64, currency: Currency) -> Result<Self> {
+        if !currency.is_known() {
+            return Err(Error::UnknownCurrency(currency));
+        }
+        major
+            .checked_mul(Self::minor_per_major(&currency))
+            .map(|amount| Self::new(amount, currency))
+            .ok_or(Error::AmountOverflow)
+    }
+
+    /// Parses a human-formatted amount such as `₱1,234.50`. See [`Currency::parse_amount`] for the
+    /// accepted formats.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ParseAmount`] if the input is not a valid amount of the currency or the
+    /// currency is a [`Currency::Unknown`], and [`Error::AmountOverflow`] if it does not fit in an
+    /// `i64`.
+    pub fn parse(input: &str, currency: Currency) -> Result<Self> {
+        let amount = currency.parse_amount(input)?;
+        Self::from_minor(amount, currency)
+    }
+
+    /// Returns the amount in the smallest currency unit.
+    #[must_use]
+    pub const fn amount(&self) -> i64 {
+        self.amount
+    }
+
+    /// Returns the currency of the amount.
+    #[must_use]
+    pub const fn currency(&self) -> &Currency {
+        &self.currency
+    }
+
+    /// Returns the whole major units of the amount, truncated towards zero.
+    #[must_use]
+    pub fn major(&self) -> i64 {
+        self.amount / Self::minor_per_major(&self.currency)
+    }
+
+    /// Returns the fractional part of the amount in the smallest currency unit, without its sign.
+    #[must_use]
+    pub fn minor(&self) -> u64 {
+        (self.amount % Self::minor_per_major(&self.currency)).unsigned_abs()
+    }
+
+    /// Returns `true` if the amount is zero.
+    #[must_use]
+    pub const fn is_zero(&self) -> bool {
+        self.amount == 0
+    }
+
+    /// Returns `true` if the amount is below zero.
+    #[must_use]
+    pub const fn is_negative(&self) -> bool {
+        self.amount < 0
+    }
+
+    /// Returns the amount as the unsigned integer sent to PayRex.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidRequest`] if the amount is negative.
+    pub fn to_minor(&self) -> Result<u64> {
+        u64::try_from(self.amount).map_err(|_| {
+            Error::InvalidRequest(format!("amount must not be negative, got {}", self.amount))
+        })
+    }
+
+    /// Adds two amounts of the same currency.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::CurrencyMismatch`] if the currencies differ, and
+    /// [`Error::AmountOverflow`] if the sum overflows.
+    pub fn checked_add(&self, other: &Self) -> Result<Self> {
+        self.ensure_same_currency(other)?;
+        self.amount
+            .checked_add(other.amount)
+            .map(|amount| Self::new(amount, self.currency.clone()))
+            .ok_or(Error::AmountOverflow)
+    }
+
+    /// Subtracts an amount of the same currency.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::CurrencyMismatch`] if the currencies differ, and
+    /// [`Error::AmountOverflow`] if the difference overflows.
+    pub fn checked_sub(&self, other: &Self) -> Result<Self> {
+        self.ensure_same_currency(other)?;
+        self.amount
+            .checked_sub(other.amount)
+            .map(|amount| Self::new(amount, self.currency.clone()))
+            .ok_or(Error::AmountOverflow)
+    }
+
+    /// Multiplies the amount by a factor.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::AmountOverflow`] if the product overflows.
+    pub fn checked_mul(&self, factor: i64) -> Result<Self> {
+        self.amount
+            .checked_mul(factor)
+            .map(|amount| Self::new(amount, self.currency.clone()))
+            .ok_or(Error::AmountOverflow)
+    }
+
+    /// Computes the total of `quantity` items sold at `unit_price`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::AmountOverflow`] if the total overflows.
+    pub fn from_unit_price(unit_price: &Self, quantity: u64) -> Result<Self> {
+        let quantity = i64::try_from(quantity).map_err(|_| Error::AmountOverflow)?;
+        unit_price.checked_mul(quantity)
+    }
+
+    /// Splits the amount into parts proportional to `ratios`, e.g. `&[70, 30]` for a 70/30 split.
+    ///
+    /// Each part is rounded towards zero to the smallest currency unit, and the remaining units are
+    /// handed out one at a time to the parts with the largest fractional remainders, so the parts
+    /// always add up to the original amount. A part with a ratio of zero never receives any units.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidRequest`] if `ratios` is empty or adds up to zero.
+    pub fn allocate(&self, ratios: &[u32]) -> Result<Vec<Self>> {
+        let total: i128 = ratios.iter().map(|&ratio| i128::from(ratio)).sum();
+        if total == 0 {
+            return Err(Error::InvalidRequest(
+                "allocation ratios must add up to more than zero".to_string(),
+            ));
+        }
+
+        let amount = i128::from(self.amount);
+        let shares: Vec<i128> = ratios
+            .iter()
+            .map(|&ratio| amount * i128::from(ratio))
+            .collect();
+        let mut parts: Vec<i128> = shares.iter().map(|share| share / total).collect();
+
+        let mut by_remainder: Vec<usize> = (0..ratios.len())
+            .filter(|&index| ratios[index] != 0)
+            .collect();
+        by_remainder
+            .sort_by_key(|&index| std::cmp::Reverse((shares[index] % total).unsigned_abs()));
+
+        let remainder = amount - parts.iter().sum::<i128>();
+        let step = remainder.signum();
+        for &index in by_remainder.iter().take(remainder.unsigned_abs() as usize) {
+            parts[index] += step;
+        }
+
+        parts
+            .into_iter()
+            .map(|part| {
+                i64::try_from(part)
+                    .map(|part| Self::new(part, self.currency.clone()))
+                    .map_err(|_| Error::AmountOverflow)
+            })
+            .collect()
+    }
+
+    /// Formats the amount according to the [`FormatOptions`]. See
+    /// [`Currency::format_amount_with`].
+    #[must_use]
+    pub fn format(&self, options: FormatOptions) -> String {
+        self.currency.format_amount_with(self.amount, options)
+    }
+
+    fn ensure_same_currency(&self, other: &Self) -> Result<()> {
+        if self.currency == other.currency {
+            Ok(())
+        } else {
+            Err(Error::CurrencyMismatch {
+                expected: self.currency.clone(),
+                actual: other.currency.clone(),
+            })
+        }
+    }
+
+    fn minor_per_major(currency: &Currency) -> i64 {
+        10_i64.pow(u32::from(currency.decimal_places()))
+    }
+}
+
+impl fmt::Display for Money {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.format(FormatOptions::new()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::ParseAmountError;
+
+    #[test]
+    fn test_money_checked_arithmetic() {
+        let price = Money::new(10_050, Currency::PHP);
+
+        assert_eq!(price.checked_add(&price).unwrap().amount(), 20_100);
+        assert_eq!(
+            price.checked_sub(&price).unwrap(),
+            Money::zero(Currency::PHP)
+        );
+        assert_eq!(price.checked_mul(-2).unwrap().amount(), -20_100);
+        assert!(matches!(
+            Money::new(i64::MAX, Currency::PHP).checked_add(&Money::new(1, Currency::PHP)),
+            Err(Error::AmountOverflow)
+        ));
+    }
+
+    #[test]
+    fn test_money_from_unit_price() {
+        let total = Money::from_unit_price(&Money::new(2_500, Currency::PHP), 4).unwrap();
+        assert_eq!(total.amount(), 10_000);
+        assert!(Money::from_unit_price(&Money::new(2, Currency::PHP), u64::MAX).is_err());
+    }
+
+    #[test]
+    fn test_money_allocate() {
+        let parts = Money::new(100, Currency::PHP).allocate(&[1, 1, 1]).unwrap();
+        let amounts: Vec<i64> = parts.iter().map(|part| part.amount()).collect();
+        assert_eq!(amounts, vec![34, 33, 33]);
+
+        let parts = Money::new(-100, Currency::PHP)
+            .allocate(&[1, 1, 1])
+            .unwrap();
+        let amounts: Vec<i64> = parts.iter().map(|part| part.amount()).collect();
+        assert_eq!(amounts, vec![-34, -33, -33]);
+
+        let parts = Money::new(10_001, Currency::PHP)
+            .allocate(&[70, 30])
+            .unwrap();
+        let amounts: Vec<i64> = parts.iter().map(|part| part.amount()).collect();
+        assert_eq!(amounts, vec![7_001, 3_000]);
+
+        let parts = Money::new(101, Currency::PHP).allocate(&[0, 1, 1]).unwrap();
+        let amounts: Vec<i64> = parts.iter().map(|part| part.amount()).collect();
+        assert_eq!(amounts, vec![0, 51, 50]);
+
+        let parts = Money::new(100, Currency::PHP).allocate(&[1, 2]).unwrap();
+        let amounts: Vec<i64> = parts.iter().map(|part| part.amount()).collect();
+        assert_eq!(amounts, vec![33, 67]);
+
+        assert!(Money::new(100, Currency::PHP).allocate(&[]).is_err());
+        assert!(Money::new(100, Currency::PHP).allocate(&[0, 0]).is_err());
+    }
+
+    #[test]
+    fn test_money_major_units() {
+        let money = Money::from_major(120, Currency::PHP).unwrap();
+        assert_eq!(money.amount(), 12_000);
+
+        let money = Money::new(-12_050, Currency::PHP);
+        assert_eq!(money.major(), -120);
+        assert_eq!(money.minor(), 50);
+
+        let jpy = Currency::Unknown("JPY".to_string());
+        assert!(matches!(
+            Money::from_major(120, jpy.clone()),
+            Err(Error::UnknownCurrency(_))
+        ));
+        assert!(matches!(
+            Money::parse("120", jpy),
+            Err(Error::ParseAmount(ParseAmountError::UnknownCurrency(_)))
+        ));
+    }
+
+    #[test]
+    fn test_money_to_minor() {
+        assert_eq!(Money::new(500, Currency::PHP).to_minor().unwrap(), 500);
+        assert!(Money::new(-500, Currency::PHP).to_minor().is_err());
+        assert_eq!(
+            Money::from_minor(500, Currency::PHP).unwrap(),
+            Money::new(500, Currency::PHP)
+        );
+    }
+
+    #[test]
+    fn test_money_parse() {
+        assert_eq!(
+            Money::parse("₱1,234.50", Currency::PHP).unwrap(),
+            Money::new(123_450, Currency::PHP)
+        );
+        assert!(matches!(
+            Money::parse("1,2345", Currency::PHP),
+            Err(Error::ParseAmount(_))
+        ));
+    }
+
+    #[test]
+    fn test_money_display() {
+        assert_eq!(Money::new(10_050, Currency::PHP).to_string(), "₱100.50");
+        assert_eq!(Money::new(-10_050, Currency::PHP).to_string(), "-₱100.50");
+        assert_eq!(
+            Money::new(-123_450, Currency::PHP).format(FormatOptions::new().grouping(true)),
+            "-₱1,234.50"
+        );
+    }
+
+    #[test]
+    fn test_money_serialization() {
+        let json = serde_json::to_value(Money::new(10_050, Currency::PHP)).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({ "amount": 10050, "currency": "PHP" })
+        );
+    }
+}
@@ -1,8 +1,22 @@
 //! Pagination support for list endpoints.
 //!
-//! PayRex uses cursor-based pagination for list endpoints.
-
+//! PayRex uses cursor-based pagination for list endpoints. [`ListStream`] walks every page of a
+//! list endpoint lazily, using the ID of the last item of a page as the `after` cursor of the next.
+
+use crate::{
+    Result,
+    types::{ObjectType, Resource},
+};
+use futures::{
+    Stream, TryStreamExt,
+    stream::{self, BoxStream},
+};
 use serde::{Deserialize, Serialize};
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
 
 /// Represents the collection for list parameters used in list endpoints in the API.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -103,6 +117,10 @@ pub struct ListParams {
     #[serde(skip_serializing_if = "Option::is_none")]
     //#[payrex(description = "Sets the page number to search before in a list.")]
     pub before: Option<String>,
+
+    /// The names of the fields to expand in each resource of the list, e.g. `customer`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub expand: Vec<String>,
 }
 
 impl ListParams {
@@ -113,6 +131,7 @@ impl ListParams {
             limit: None,
             after: None,
             before: None,
+            expand: Vec::new(),
         }
     }
 
@@ -136,11 +155,270 @@ impl ListParams {
         self.before = Some(id.into());
         self
     }
+
+    /// Adds a field to expand in each resource of the list.
+    #[must_use]
+    pub fn expand(mut self, field: impl Into<String>) -> Self {
+        self.expand.push(field.into());
+        self
+    }
+}
+
+/// List parameters that carry the baseline [`ListParams`] used as the pagination cursor.
+pub(crate) trait Paginate: Clone + Send + 'static {
+    /// Returns the baseline pagination fields of the parameters.
+    fn list_params_mut(&mut self) -> &mut ListParams;
+}
+
+impl Paginate for ListParams {
+    fn list_params_mut(&mut self) -> &mut ListParams {
+        self
+    }
+}
+
+/// An async [`Stream`] over every item of a list endpoint, fetching the next page only once the
+/// items of the current page are consumed.
+///
+/// Pages are walked forwards with the `after` cursor, unless only `before` is set on the initial
+/// parameters, in which case they are walked backwards with the `before` cursor.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use futures::TryStreamExt;
+/// use payrex::{Client, resources::customers::CustomerListParams};
+///
+/// # async fn example() -> Result<(), payrex::Error> {
+/// let client = Client::new("your_secret_key");
+/// let customers: Vec<_> = client
+///     .customers()
+///     .list_auto(CustomerListParams::new())
+///     .max_items(500)
+///     .try_collect()
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+#[must_use = "streams do nothing unless polled"]
+pub struct ListStream<T> {
+    inner: BoxStream<'static, Result<T>>,
+    max_items: Option<usize>,
+    yielded: usize,
+}
+
+impl<T> ListStream<T> {
+    /// Stops the stream once `max_items` items have been yielded, without fetching further pages.
+    pub const fn max_items(mut self, max_items: usize) -> Self {
+        self.max_items = Some(max_items);
+        self
+    }
+}
+
+impl<T> Stream for ListStream<T> {
+    type Item = Result<T>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        if self.max_items.is_some_and(|max| self.yielded >= max) {
+            return Poll::Ready(None);
+        }
+
+        let item = self.inner.as_mut().poll_next(cx);
+        if let Poll::Ready(Some(Ok(_))) = item {
+            self.yielded += 1;
+        }
+        item
+    }
+}
+
+impl<T> std::fmt::Debug for ListStream<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ListStream")
+            .field("max_items", &self.max_items)
+            .field("yielded", &self.yielded)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Builds a [`ListStream`] that calls `fetch` for each page, starting with `params`.
+pub(crate) fn paginate<T, P, F, Fut>(params: P, mut fetch: F) -> ListStream<T>
+where
+    T: Resource + Send + 'static,
+    T::Id: AsRef<str>,
+    P: Paginate,
+    F: FnMut(P) -> Fut + Send + 'static,
+    Fut: Future<Output = Result<List<T>>> + Send + 'static,
+{
+    let mut initial = params;
+    let backwards = {
+        let list_params = initial.list_params_mut();
+        list_params.after.is_none() && list_params.before.is_some()
+    };
+
+    let pages = stream::unfold(Some(initial), move |state| {
+        let request = state.map(|params| (fetch(params.clone()), params));
+
+        async move {
+            let (page, mut params) = request?;
+
+            match page.await {
+                Ok(list) => {
+                    let cursor = if backwards {
+                        list.data.first()
+                    } else {
+                        list.data.last()
+                    };
+                    let cursor = cursor
+                        .filter(|_| list.has_more)
+                        .map(|item| item.id().as_ref().to_owned());
+
+                    let next = cursor.map(|cursor| {
+                        let list_params = params.list_params_mut();
+                        if backwards {
+                            list_params.before = Some(cursor);
+                        } else {
+                            list_params.after = Some(cursor);
+                        }
+                        params
+                    });
+
+                    Some((Ok(list), next))
+                }
+                Err(e) => Some((Err(e), None)),
+            }
+        }
+    });
+
+    ListStream {
+        inner: Box::pin(
+            pages
+                .map_ok(|list| stream::iter(list.data.into_iter().map(Ok)))
+                .try_flatten(),
+        ),
+        max_items: None,
+        yielded: 0,
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use futures::StreamExt;
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    struct Item(String);
+
+    impl Resource for Item {
+        type Id = String;
+
+        fn id(&self) -> &Self::Id {
+            &self.0
+        }
+
+        fn object_type() -> ObjectType {
+            ObjectType::List
+        }
+    }
+
+    fn page(ids: &[&str], has_more: bool) -> List<Item> {
+        List {
+            object: Some("list".to_string()),
+            data: ids.iter().map(|id| Item((*id).to_string())).collect(),
+            has_more,
+            next_page: None,
+            total_count: None,
+        }
+    }
+
+    type Requests = Arc<Mutex<Vec<ListParams>>>;
+
+    fn mock_pages(
+        pages: Vec<List<Item>>,
+    ) -> (
+        Requests,
+        impl FnMut(ListParams) -> futures::future::Ready<Result<List<Item>>> + Send + 'static,
+    ) {
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let recorded = Arc::clone(&requests);
+        let mut pages = pages.into_iter();
+
+        let fetch = move |params: ListParams| {
+            recorded.lock().unwrap().push(params);
+            futures::future::ready(Ok(pages.next().unwrap_or_default()))
+        };
+
+        (requests, fetch)
+    }
+
+    #[tokio::test]
+    async fn test_paginate_forwards() {
+        let (requests, fetch) = mock_pages(vec![page(&["a", "b"], true), page(&["c"], false)]);
+        let items: Vec<_> = paginate(ListParams::new().limit(2), fetch)
+            .try_collect()
+            .await
+            .unwrap();
+
+        assert_eq!(
+            items,
+            vec![Item("a".into()), Item("b".into()), Item("c".into())]
+        );
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].after, None);
+        assert_eq!(requests[1].after.as_deref(), Some("b"));
+        assert_eq!(requests[1].limit, Some(2));
+    }
+
+    #[tokio::test]
+    async fn test_paginate_backwards() {
+        let (requests, fetch) = mock_pages(vec![page(&["c", "d"], true), page(&["b"], false)]);
+        let items: Vec<_> = paginate(ListParams::new().before("e"), fetch)
+            .try_collect()
+            .await
+            .unwrap();
+
+        assert_eq!(items.len(), 3);
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests[1].before.as_deref(), Some("c"));
+        assert_eq!(requests[1].after, None);
+    }
+
+    #[tokio::test]
+    async fn test_paginate_max_items() {
+        let (requests, fetch) = mock_pages(vec![
+            page(&["a", "b"], true),
+            page(&["c", "d"], true),
+            page(&["e"], false),
+        ]);
+        let items: Vec<_> = paginate(ListParams::new(), fetch)
+            .max_items(3)
+            .try_collect()
+            .await
+            .unwrap();
+
+        assert_eq!(items.len(), 3);
+        assert_eq!(requests.lock().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_paginate_stops_on_error() {
+        let mut calls = 0;
+        let fetch = move |_: ListParams| {
+            calls += 1;
+            futures::future::ready(if calls == 1 {
+                Ok(page(&["a"], true))
+            } else {
+                Err(crate::Error::Internal("boom".to_string()))
+            })
+        };
+        let items: Vec<_> = paginate(ListParams::new(), fetch).collect().await;
+
+        assert_eq!(items.len(), 2);
+        assert!(items[0].is_ok());
+        assert!(items[1].is_err());
+    }
 
     #[test]
     fn test_list_empty() {
@@ -3,7 +3,7 @@
 use serde::{Deserialize, Serialize};
 
 /// Available payment methods for a payment intent.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum PaymentMethod {
     /// Card payments
     #[serde(rename = "card")]
@@ -20,17 +20,22 @@ pub enum PaymentMethod {
     /// QRPH payments
     #[serde(rename = "qrph")]
     QRPh,
+
+    /// A payment method not known to this version of the SDK, holding the original value.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl PaymentMethod {
     /// Returns the string representation of the payment method.
     #[must_use]
-    pub const fn as_str(self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             Self::Card => "card",
             Self::GCash => "gcash",
             Self::Maya => "maya",
             Self::QRPh => "qrph",
+            Self::Unknown(method) => method,
         }
     }
 }
@@ -126,4 +131,12 @@ mod tests {
         assert_eq!(Maya.as_str(), "maya");
         assert_eq!(QRPh.as_str(), "qrph");
     }
+
+    #[test]
+    fn test_payment_method_unknown_round_trip() {
+        let method: PaymentMethod = serde_json::from_str("\"bank_transfer\"").unwrap();
+        assert_eq!(method, PaymentMethod::Unknown("bank_transfer".to_string()));
+        assert_eq!(method.as_str(), "bank_transfer");
+        assert_eq!(serde_json::to_string(&method).unwrap(), "\"bank_transfer\"");
+    }
 }
@@ -1,4 +1,4 @@
-use payrex::{Config, ConfigBuilder, Error};
+use payrex::{Config, ConfigBuilder, Error, ExecutionMode};
 use serde_json::Value;
 
 pub const TEST_API_KEY: &str = "your_api_key";
@@ -11,6 +11,30 @@ pub fn mock_config(api_base_url: impl AsRef<str>) -> Result<Config, Error> {
         .build()
 }
 
+pub fn mock_config_with_execution_mode(
+    api_base_url: impl AsRef<str>,
+    execution_mode: ExecutionMode,
+) -> Result<Config, Error> {
+    ConfigBuilder::new()
+        .api_key(TEST_API_KEY)
+        .api_base_url(api_base_url.as_ref())
+        .test_mode(true)
+        .execution_mode(execution_mode)
+        .build()
+}
+
+pub fn mock_config_with_check_livemode(
+    api_base_url: impl AsRef<str>,
+    check_livemode: bool,
+) -> Result<Config, Error> {
+    ConfigBuilder::new()
+        .api_key(TEST_API_KEY)
+        .api_base_url(api_base_url.as_ref())
+        .test_mode(true)
+        .check_livemode(check_livemode)
+        .build()
+}
+
 pub fn create_json_fixture(file_content: &str) -> Value {
     let json_body: Value = serde_json::from_str(file_content)
         .expect("File content must be encoded as UTF-8 and must follow the JSON format.");
@@ -1,5 +1,8 @@
 mod helpers;
 
 pub use helpers::TEST_API_KEY;
-pub use helpers::{create_json_fixture, mock_config};
+pub use helpers::{
+    create_json_fixture, mock_config, mock_config_with_check_livemode,
+    mock_config_with_execution_mode,
+};
 pub type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
@@ -5,7 +5,7 @@ use payrex::resources::billing_statement_line_items::{
     CreateBillingStatementLineItem, UpdateBillingStatementLineItem,
 };
 use payrex::types::{BillingStatementId, BillingStatementLineItemId};
-use serde_json::{Number, Value};
+use serde_json::{Number, Value, json};
 use wiremock::{
     Mock, MockBuilder, MockServer, ResponseTemplate,
     matchers::{basic_auth, body_string_contains, method, path},
@@ -16,6 +16,7 @@ use crate::support::{Result, TEST_API_KEY, create_json_fixture, mock_config};
 const LINE_ITEM_FIXTURE: &str = include_str!("fixtures/billing-statement-line-item.json");
 const ERR_LINE_ITEM_FIXTURE: &str =
     include_str!("fixtures/err-put-billing-statement-line-item.json");
+const BILLING_STATEMENT_FIXTURE: &str = include_str!("fixtures/billing-statement.json");
 
 fn mock_line_item_builder(method_str: &str, path_param: Option<&str>) -> MockBuilder {
     Mock::given(method(method_str))
@@ -26,6 +27,15 @@ fn mock_line_item_builder(method_str: &str, path_param: Option<&str>) -> MockBui
         .and(basic_auth(TEST_API_KEY, ""))
 }
 
+fn mock_billing_statement_builder(method_str: &str, path_param: Option<&str>) -> MockBuilder {
+    Mock::given(method(method_str))
+        .and(path(format!(
+            "/billing_statements{}",
+            path_param.unwrap_or("")
+        )))
+        .and(basic_auth(TEST_API_KEY, ""))
+}
+
 #[tokio::test]
 async fn test_create_billing_statement_line_item_ok() -> Result<()> {
     let mock_server = MockServer::start().await;
@@ -105,3 +115,54 @@ async fn test_update_billing_statement_line_item_error() -> Result<()> {
     assert!(err.to_string().contains("resource_invalid_state"));
     Ok(())
 }
+
+#[tokio::test]
+async fn test_list_billing_statement_line_items_ok() -> Result<()> {
+    let mock_server = MockServer::start().await;
+    let stmt_id = "bstm_f4rdf8645sMBn44osn2ttXgrM8FnUT5U";
+    let json_body = create_json_fixture(BILLING_STATEMENT_FIXTURE);
+
+    mock_billing_statement_builder("GET", Some(format!("/{stmt_id}").as_ref()))
+        .and(body_string_contains("line_items"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json_body))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let config = mock_config(mock_server.uri())?;
+    let client = Client::with_config(config)?;
+    let id = BillingStatementId::new(stmt_id);
+    let items = client.billing_statement_line_items().list(&id).await?;
+
+    assert_eq!(items.len(), 1);
+    assert_eq!(
+        items[0].id,
+        BillingStatementLineItemId::new("bstm_li_etTeXvtDUVQnE86m3cUQ7Y6AzgKMGpur")
+    );
+    assert!(items.iter().all(|item| item.billing_statement_id == id));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_delete_billing_statement_line_item_ok() -> Result<()> {
+    let mock_server = MockServer::start().await;
+    let item_id = "bstm_li_etTeXvtDUVQnE86m3cUQ7Y6AzgKMGpur";
+
+    mock_line_item_builder("DELETE", Some(format!("/{item_id}").as_ref()))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": item_id,
+            "resource": "billing_statement_line_item",
+            "deleted": true
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let config = mock_config(mock_server.uri())?;
+    let client = Client::with_config(config)?;
+    let id = BillingStatementLineItemId::new(item_id);
+    let deleted = client.billing_statement_line_items().delete(&id).await?;
+
+    assert_eq!(deleted.id, id);
+    assert!(deleted.deleted);
+    Ok(())
+}
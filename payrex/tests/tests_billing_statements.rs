@@ -1,17 +1,23 @@
 mod support;
 
 use payrex::Client;
-use payrex::resources::billing_statements::{CreateBillingStatement, UpdateBillingStatement};
-use payrex::types::{BillingStatementId, Currency, CustomerId};
-use serde_json::Value;
+use payrex::resources::billing_statements::{
+    BillingStatementListParams, BillingStatementStatus, CreateBillingStatement,
+    UpdateBillingStatement,
+};
+use payrex::types::{
+    BillingStatementId, Currency, CustomerId, ExpandParams, RangeQuery, Timestamp,
+};
+use serde_json::{Value, json};
 use wiremock::{
     Mock, MockBuilder, MockServer, ResponseTemplate,
-    matchers::{basic_auth, method, path},
+    matchers::{basic_auth, body_string_contains, method, path},
 };
 
 use crate::support::{Result, TEST_API_KEY, create_json_fixture, mock_config};
 
 const BILLING_STATEMENT_FIXTURE: &str = include_str!("fixtures/billing-statement.json");
+const CUSTOMER_FIXTURE: &str = include_str!("fixtures/customer.json");
 
 fn mock_billing_statement_builder(method_str: &str, path_param: Option<&str>) -> MockBuilder {
     Mock::given(method(method_str))
@@ -167,3 +173,67 @@ async fn test_mark_uncollectible_billing_statement_ok() -> Result<()> {
     assert_eq!(response.id, id);
     Ok(())
 }
+
+#[tokio::test]
+async fn test_retrieve_billing_statement_expanded_ok() -> Result<()> {
+    let mock_server = MockServer::start().await;
+    let mut json_body = create_json_fixture(BILLING_STATEMENT_FIXTURE);
+    let stmt_id = "bstm_f4rdf8645sMBn44osn2ttXgrM8FnUT5U";
+
+    json_body["customer"] = create_json_fixture(CUSTOMER_FIXTURE);
+
+    mock_billing_statement_builder("GET", Some(format!("/{stmt_id}").as_ref()))
+        .and(body_string_contains("expand"))
+        .and(body_string_contains("=customer"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json_body))
+        .mount(&mock_server)
+        .await;
+
+    let config = mock_config(mock_server.uri())?;
+    let client = Client::with_config(config)?;
+    let id = BillingStatementId::new(stmt_id);
+    let params = ExpandParams::new().expand("customer");
+    let response = client
+        .billing_statements()
+        .retrieve_with_params(&id, params)
+        .await?;
+
+    let customer = response
+        .customer
+        .and_then(|customer| customer.into_object())
+        .expect("customer should be expanded");
+    assert_eq!(
+        customer.billing_statement_prefix.as_deref(),
+        Some("SAB9EQZG")
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_list_billing_statements_filtered_ok() -> Result<()> {
+    let mock_server = MockServer::start().await;
+    let json_body = json!({
+        "resource": "list",
+        "data": [create_json_fixture(BILLING_STATEMENT_FIXTURE)],
+        "has_more": false
+    });
+
+    mock_billing_statement_builder("GET", None)
+        .and(body_string_contains("customer_id=cus_123"))
+        .and(body_string_contains("status=open"))
+        .and(body_string_contains("due_at[lt]=1700000000"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json_body))
+        .mount(&mock_server)
+        .await;
+
+    let config = mock_config(mock_server.uri())?;
+    let client = Client::with_config(config)?;
+    let params = BillingStatementListParams::new()
+        .customer_id(CustomerId::new("cus_123"))
+        .status(BillingStatementStatus::Open)
+        .due_at(RangeQuery::new().lt(Timestamp::from_unix(1_700_000_000)));
+    let list = client.billing_statements().list(Some(params)).await?;
+
+    assert_eq!(list.data.len(), 1);
+    Ok(())
+}
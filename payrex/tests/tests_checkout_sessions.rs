@@ -2,13 +2,14 @@ mod support;
 
 use payrex::Client;
 use payrex::resources::checkout_sessions::{
-    CheckoutSessionLineItem, CheckoutSessionStatus, CreateCheckoutSession,
+    CheckoutSessionLineItem, CheckoutSessionListParams, CheckoutSessionStatus,
+    CreateCheckoutSession,
 };
 use payrex::types::{CheckoutSessionId, Currency, PaymentMethod};
-use serde_json::Value;
+use serde_json::{Value, json};
 use wiremock::{
     Mock, MockBuilder, MockServer, ResponseTemplate,
-    matchers::{basic_auth, method, path},
+    matchers::{basic_auth, body_string_contains, method, path},
 };
 
 use crate::support::{Result, TEST_API_KEY, create_json_fixture, mock_config};
@@ -97,3 +98,34 @@ async fn test_expire_checkout_session_ok() -> Result<()> {
     assert_eq!(response.status, CheckoutSessionStatus::Expired);
     Ok(())
 }
+
+#[tokio::test]
+async fn test_list_checkout_sessions_ok() -> Result<()> {
+    let mock_server = MockServer::start().await;
+    let json_body = json!({
+        "resource": "list",
+        "data": [create_json_fixture(CHECKOUT_SESSION_FIXTURE)],
+        "has_more": false
+    });
+
+    mock_checkout_session_builder("GET", None)
+        .and(body_string_contains("status=expired"))
+        .and(body_string_contains("customer_reference_id=order_123"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json_body))
+        .mount(&mock_server)
+        .await;
+
+    let config = mock_config(mock_server.uri())?;
+    let client = Client::with_config(config)?;
+    let params = CheckoutSessionListParams::new()
+        .status(CheckoutSessionStatus::Expired)
+        .customer_reference_id("order_123");
+    let list = client.checkout_sessions().list(Some(params)).await?;
+
+    assert_eq!(list.data.len(), 1);
+    assert_eq!(
+        list.data[0].id,
+        CheckoutSessionId::new("cs_CuzUcxGRJ9UL3KxBSSAxhm5EhBGWMquu")
+    );
+    Ok(())
+}
@@ -1,16 +1,21 @@
 mod support;
 
-use payrex::Client;
 use payrex::Error as PayrexError;
-use payrex::resources::customers::UpdateCustomer;
-use payrex::types::{CustomerId, Metadata};
-use serde_json::Value;
+use payrex::resources::customers::{
+    CreateCustomer, DuplicateStrategy, UpdateCustomer, UpsertOptions,
+};
+use payrex::types::{Currency, CustomerId, Metadata};
+use payrex::{Client, ExecutionMode, Mode};
+use serde_json::{Value, json};
 use wiremock::{
     Mock, MockBuilder, MockServer, ResponseTemplate,
     matchers::{basic_auth, body_string_contains, method, path},
 };
 
-use crate::support::{Result, TEST_API_KEY, create_json_fixture, mock_config};
+use crate::support::{
+    Result, TEST_API_KEY, create_json_fixture, mock_config, mock_config_with_check_livemode,
+    mock_config_with_execution_mode,
+};
 
 const CUSTOMER_FIXTURE: &str = include_str!("fixtures/customer.json");
 
@@ -73,3 +78,313 @@ async fn test_update_customer_ok() -> Result<()> {
     assert_eq!(response.email.as_deref(), Some("new@example.com"));
     Ok(())
 }
+
+#[tokio::test]
+async fn test_retrieve_customer_livemode_mismatch() -> Result<()> {
+    let mock_server = MockServer::start().await;
+    let mut json_body = create_json_fixture(CUSTOMER_FIXTURE);
+    let customer_id = "cus_8Te4pwkR5ePwG2UVsY2NTJyVDXYaVQLX";
+
+    json_body["livemode"] = Value::Bool(true);
+
+    mock_customer_builder("GET", Some(format!("/{customer_id}").as_ref()))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json_body))
+        .mount(&mock_server)
+        .await;
+
+    let config = mock_config_with_check_livemode(mock_server.uri(), true)?;
+    let client = Client::with_config(config)?;
+    let id = CustomerId::new(customer_id);
+    let err = client.customers().retrieve(&id).await.unwrap_err();
+
+    assert!(matches!(
+        err,
+        PayrexError::LivemodeMismatch {
+            expected: Mode::Test,
+            actual: Mode::Live,
+        }
+    ));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_retrieve_customer_livemode_check_disabled() -> Result<()> {
+    let mock_server = MockServer::start().await;
+    let mut json_body = create_json_fixture(CUSTOMER_FIXTURE);
+    let customer_id = "cus_8Te4pwkR5ePwG2UVsY2NTJyVDXYaVQLX";
+
+    json_body["livemode"] = Value::Bool(true);
+
+    mock_customer_builder("GET", Some(format!("/{customer_id}").as_ref()))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json_body))
+        .mount(&mock_server)
+        .await;
+
+    let config = mock_config_with_check_livemode(mock_server.uri(), false)?;
+    let client = Client::with_config(config)?;
+    let id = CustomerId::new(customer_id);
+    let customer = client.customers().retrieve(&id).await?;
+
+    assert_eq!(customer.id, id);
+    assert!(customer.livemode);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_customer_read_only() -> Result<()> {
+    let mock_server = MockServer::start().await;
+    let customer_id = "cus_8Te4pwkR5ePwG2UVsY2NTJyVDXYaVQLX";
+
+    mock_customer_builder("PUT", Some(format!("/{customer_id}").as_ref()))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let config = mock_config_with_execution_mode(mock_server.uri(), ExecutionMode::ReadOnly)?;
+    let client = Client::with_config(config)?;
+    let id = CustomerId::new(customer_id);
+    let params = UpdateCustomer::new().email("new@example.com");
+    let err = client.customers().update(&id, params).await.unwrap_err();
+
+    assert!(matches!(err, PayrexError::ReadOnly { .. }));
+    assert!(client.dry_run_report().is_empty());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_customer_dry_run() -> Result<()> {
+    let mock_server = MockServer::start().await;
+    let customer_id = "cus_8Te4pwkR5ePwG2UVsY2NTJyVDXYaVQLX";
+
+    mock_customer_builder("PUT", Some(format!("/{customer_id}").as_ref()))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let config = mock_config_with_execution_mode(mock_server.uri(), ExecutionMode::DryRun)?;
+    let client = Client::with_config(config)?;
+    let id = CustomerId::new(customer_id);
+    let params = UpdateCustomer::new().email("new@example.com");
+    let err = client.customers().update(&id, params).await.unwrap_err();
+
+    assert!(matches!(err, PayrexError::DryRun { .. }));
+
+    let report = client.take_dry_run_report();
+    assert_eq!(report.len(), 1);
+    assert_eq!(report[0].method, "PUT");
+    assert_eq!(report[0].path, format!("/customers/{customer_id}"));
+    assert!(report[0].body.contains("email=new%40example.com"));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_retrieve_customer_wrong_resource() -> Result<()> {
+    let mock_server = MockServer::start().await;
+    let mut json_body = create_json_fixture(CUSTOMER_FIXTURE);
+    let customer_id = "cus_8Te4pwkR5ePwG2UVsY2NTJyVDXYaVQLX";
+
+    json_body["resource"] = Value::String("payment".to_string());
+
+    mock_customer_builder("GET", Some(format!("/{customer_id}").as_ref()))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json_body))
+        .mount(&mock_server)
+        .await;
+
+    let config = mock_config(mock_server.uri())?;
+    let client = Client::with_config(config)?;
+    let id = CustomerId::new(customer_id);
+    let err = client.customers().retrieve(&id).await.unwrap_err();
+
+    assert!(matches!(err, PayrexError::Json(_)));
+    assert!(err.to_string().contains("expected a `customer` resource"));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_delete_customer_no_content() -> Result<()> {
+    let mock_server = MockServer::start().await;
+    let customer_id = "cus_8Te4pwkR5ePwG2UVsY2NTJyVDXYaVQLX";
+
+    mock_customer_builder("DELETE", Some(format!("/{customer_id}").as_ref()))
+        .respond_with(ResponseTemplate::new(204))
+        .mount(&mock_server)
+        .await;
+
+    let config = mock_config(mock_server.uri())?;
+    let client = Client::with_config(config)?;
+    let id = CustomerId::new(customer_id);
+    let deleted = client.customers().delete(&id).await?;
+
+    assert_eq!(deleted.id, id);
+    assert!(deleted.deleted);
+    Ok(())
+}
+
+fn duplicate_customers_body() -> Value {
+    let older = create_json_fixture(CUSTOMER_FIXTURE);
+    let mut newer = older.clone();
+    newer["id"] = Value::String("cus_NewerCustomerWithSameEmail00000".to_string());
+    newer["email"] = Value::String("TestDev@gmail.com".to_string());
+    newer["created_at"] = json!(1_760_000_000);
+
+    json!({
+        "resource": "list",
+        "data": [older, newer],
+        "has_more": false
+    })
+}
+
+#[tokio::test]
+async fn test_find_customer_by_email_duplicates() -> Result<()> {
+    let mock_server = MockServer::start().await;
+
+    mock_customer_builder("GET", None)
+        .and(body_string_contains("email=testdev%40gmail.com"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(duplicate_customers_body()))
+        .mount(&mock_server)
+        .await;
+
+    let config = mock_config(mock_server.uri())?;
+    let client = Client::with_config(config)?;
+
+    let err = client
+        .customers()
+        .find_by_email("testdev@gmail.com", DuplicateStrategy::Error)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, PayrexError::DuplicateCustomer { ref ids, .. } if ids.len() == 2));
+
+    let customer = client
+        .customers()
+        .find_by_email("testdev@gmail.com", DuplicateStrategy::NewestWins)
+        .await?
+        .expect("a customer should match");
+    assert_eq!(
+        customer.id,
+        CustomerId::new("cus_NewerCustomerWithSameEmail00000")
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_upsert_customer_creates_when_missing() -> Result<()> {
+    let mock_server = MockServer::start().await;
+
+    mock_customer_builder("GET", None)
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "resource": "list",
+            "data": [],
+            "has_more": false
+        })))
+        .mount(&mock_server)
+        .await;
+
+    mock_customer_builder("POST", None)
+        .and(body_string_contains("email=testdev%40gmail.com"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(create_json_fixture(CUSTOMER_FIXTURE)),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let config = mock_config(mock_server.uri())?;
+    let client = Client::with_config(config)?;
+    let params = CreateCustomer::new("testdev@gmail.com", "Test dev", Currency::PHP);
+    let customer = client
+        .customers()
+        .upsert(params, UpsertOptions::new())
+        .await?;
+
+    assert_eq!(customer.email.as_deref(), Some("testdev@gmail.com"));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_upsert_customer_merges_metadata() -> Result<()> {
+    let mock_server = MockServer::start().await;
+    let customer_id = "cus_8Te4pwkR5ePwG2UVsY2NTJyVDXYaVQLX";
+    let mut existing = create_json_fixture(CUSTOMER_FIXTURE);
+    existing["metadata"] = json!({ "plan": "basic" });
+
+    mock_customer_builder("GET", None)
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "resource": "list",
+            "data": [existing.clone()],
+            "has_more": false
+        })))
+        .mount(&mock_server)
+        .await;
+
+    mock_customer_builder("PUT", Some(format!("/{customer_id}").as_ref()))
+        .and(body_string_contains("metadata[plan]=basic"))
+        .and(body_string_contains("metadata[source]=signup"))
+        .and(body_string_contains("name=Renamed+dev"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(existing))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    mock_customer_builder("POST", None)
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let config = mock_config(mock_server.uri())?;
+    let client = Client::with_config(config)?;
+    let params = CreateCustomer::new("testdev@gmail.com", "Renamed dev", Currency::PHP)
+        .metadata(Metadata::with_pair("source", "signup"));
+    let customer = client
+        .customers()
+        .upsert(params, UpsertOptions::new().merge_metadata(true))
+        .await?;
+
+    assert_eq!(customer.id, CustomerId::new(customer_id));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_upsert_customer_replaces_metadata() -> Result<()> {
+    let mock_server = MockServer::start().await;
+    let customer_id = "cus_8Te4pwkR5ePwG2UVsY2NTJyVDXYaVQLX";
+    let mut existing = create_json_fixture(CUSTOMER_FIXTURE);
+    existing["metadata"] = json!({ "plan": "basic" });
+
+    mock_customer_builder("GET", None)
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "resource": "list",
+            "data": [existing.clone()],
+            "has_more": false
+        })))
+        .mount(&mock_server)
+        .await;
+
+    mock_customer_builder("PUT", Some(format!("/{customer_id}").as_ref()))
+        .and(body_string_contains("metadata[plan]"))
+        .respond_with(ResponseTemplate::new(500))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    mock_customer_builder("PUT", Some(format!("/{customer_id}").as_ref()))
+        .and(body_string_contains("metadata[source]=signup"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(existing))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let config = mock_config(mock_server.uri())?;
+    let client = Client::with_config(config)?;
+    let params = CreateCustomer::new("testdev@gmail.com", "Renamed dev", Currency::PHP)
+        .metadata(Metadata::with_pair("source", "signup"));
+    let customer = client
+        .customers()
+        .upsert(params, UpsertOptions::new())
+        .await?;
+
+    assert_eq!(customer.id, CustomerId::new(customer_id));
+    Ok(())
+}
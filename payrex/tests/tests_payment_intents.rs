@@ -1,7 +1,10 @@
 mod support;
 
 use payrex::Error as PayrexError;
-use payrex::resources::payment_intents::PaymentIntentStatus;
+use payrex::resources::payment_intents::{
+    CapturePaymentIntent, PaymentIntent, PaymentIntentListParams, PaymentIntentStatus,
+    UpdatePaymentIntent,
+};
 use payrex::types::PaymentIntentId;
 use payrex::{
     Client, Config, ConfigBuilder,
@@ -82,6 +85,31 @@ async fn test_retrieve_payment_intent_ok() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_retrieve_payment_intent_preserves_unknown_fields() -> Result<()> {
+    let mock_server = MockServer::start().await;
+    let mut json_body = create_json_fixture(PAYMENT_INTENT_FIXTURE);
+    json_body["future_field"] = json!({ "enabled": true });
+    let payment_intent_id = "pi_FxmwbTkuQQb3qMBrgGiyNyzEFR7BKZVQ";
+
+    mock_payment_intent_builder("GET", Some(format!("/{payment_intent_id}").as_ref()))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json_body))
+        .mount(&mock_server)
+        .await;
+
+    let config = mock_config(mock_server.uri())?;
+    let client = Client::with_config(config)?;
+    let id = PaymentIntentId::new(payment_intent_id);
+    let response = client.payment_intents().retrieve(&id).await?;
+
+    assert!(!response.extra.contains_key("id"));
+    assert_eq!(response.extra["future_field"], json!({ "enabled": true }));
+
+    let serialized = serde_json::to_value(&response)?;
+    assert_eq!(serialized["future_field"], json!({ "enabled": true }));
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_cancel_payment_intent_ok() -> Result<()> {
     let mock_server = MockServer::start().await;
@@ -113,4 +141,168 @@ async fn test_cancel_payment_intent_ok() -> Result<()> {
     Ok(())
 }
 
-// TODO: Add mock test for capturing payment intents
+#[tokio::test]
+async fn test_capture_payment_intent_partial_ok() -> Result<()> {
+    let mock_server = MockServer::start().await;
+    let mut json_body = create_json_fixture(PAYMENT_INTENT_FIXTURE);
+    let payment_intent_id = "pi_FxmwbTkuQQb3qMBrgGiyNyzEFR7BKZVQ";
+    json_body["status"] = Value::String("requires_capture".to_string());
+    json_body["amount_capturable"] = json!(10_000);
+
+    let mut captured = json_body.clone();
+    captured["status"] = Value::String("succeeded".to_string());
+    captured["amount_capturable"] = json!(0);
+    captured["amount_received"] = json!(7_500);
+
+    mock_payment_intent_builder(
+        "POST",
+        Some(format!("/{payment_intent_id}/capture").as_ref()),
+    )
+    .and(body_string_contains("amount=7500"))
+    .respond_with(ResponseTemplate::new(200).set_body_json(captured))
+    .expect(1)
+    .mount(&mock_server)
+    .await;
+
+    let config = mock_config(mock_server.uri())?;
+    let client = Client::with_config(config)?;
+    let payment_intent: PaymentIntent = serde_json::from_value(json_body)?;
+    let response = client
+        .payment_intents()
+        .capture_checked(&payment_intent, CapturePaymentIntent::new(7_500))
+        .await?;
+
+    assert_eq!(response.status, PaymentIntentStatus::Succeeded);
+    assert_eq!(response.amount_received, 7_500);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_capture_payment_intent_full_ok() -> Result<()> {
+    let mock_server = MockServer::start().await;
+    let mut json_body = create_json_fixture(PAYMENT_INTENT_FIXTURE);
+    let payment_intent_id = "pi_FxmwbTkuQQb3qMBrgGiyNyzEFR7BKZVQ";
+    json_body["status"] = Value::String("requires_capture".to_string());
+    json_body["amount_capturable"] = json!(10_000);
+
+    mock_payment_intent_builder(
+        "POST",
+        Some(format!("/{payment_intent_id}/capture").as_ref()),
+    )
+    .and(body_string_contains("amount=10000"))
+    .respond_with(ResponseTemplate::new(200).set_body_json(json_body.clone()))
+    .expect(1)
+    .mount(&mock_server)
+    .await;
+
+    let config = mock_config(mock_server.uri())?;
+    let client = Client::with_config(config)?;
+    let payment_intent: PaymentIntent = serde_json::from_value(json_body)?;
+    let params = CapturePaymentIntent::full(&payment_intent);
+    client
+        .payment_intents()
+        .capture_checked(&payment_intent, params)
+        .await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_capture_payment_intent_exceeds_capturable() -> Result<()> {
+    let mock_server = MockServer::start().await;
+    let mut json_body = create_json_fixture(PAYMENT_INTENT_FIXTURE);
+    let payment_intent_id = "pi_FxmwbTkuQQb3qMBrgGiyNyzEFR7BKZVQ";
+    json_body["status"] = Value::String("requires_capture".to_string());
+    json_body["amount_capturable"] = json!(10_000);
+
+    mock_payment_intent_builder(
+        "POST",
+        Some(format!("/{payment_intent_id}/capture").as_ref()),
+    )
+    .respond_with(ResponseTemplate::new(200))
+    .expect(0)
+    .mount(&mock_server)
+    .await;
+
+    let config = mock_config(mock_server.uri())?;
+    let client = Client::with_config(config)?;
+    let payment_intent: PaymentIntent = serde_json::from_value(json_body)?;
+    let err = client
+        .payment_intents()
+        .capture_checked(&payment_intent, CapturePaymentIntent::new(10_001))
+        .await
+        .unwrap_err();
+
+    assert!(matches!(
+        err,
+        PayrexError::CaptureAmountExceeded {
+            requested: 10_001,
+            capturable: 10_000,
+        }
+    ));
+
+    let err = CapturePaymentIntent::new(0)
+        .validate(&payment_intent)
+        .unwrap_err();
+    assert!(matches!(err, PayrexError::InvalidRequest(_)));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_payment_intent_ok() -> Result<()> {
+    let mock_server = MockServer::start().await;
+    let mut json_body = create_json_fixture(PAYMENT_INTENT_FIXTURE);
+    let payment_intent_id = "pi_FxmwbTkuQQb3qMBrgGiyNyzEFR7BKZVQ";
+
+    json_body["amount"] = json!(20_000);
+    json_body["description"] = Value::String("Updated order".to_string());
+
+    mock_payment_intent_builder("PUT", Some(format!("/{payment_intent_id}").as_ref()))
+        .and(body_string_contains("amount=20000"))
+        .and(body_string_contains("description=Updated+order"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json_body))
+        .mount(&mock_server)
+        .await;
+
+    let config = mock_config(mock_server.uri())?;
+    let client = Client::with_config(config)?;
+    let id = PaymentIntentId::new(payment_intent_id);
+    let params = UpdatePaymentIntent::new()
+        .amount(20_000)
+        .description("Updated order");
+    let response = client.payment_intents().update(&id, params).await?;
+
+    assert_eq!(response.amount, 20_000);
+    assert_eq!(response.description.as_deref(), Some("Updated order"));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_list_payment_intents_ok() -> Result<()> {
+    let mock_server = MockServer::start().await;
+    let json_body = json!({
+        "resource": "list",
+        "data": [create_json_fixture(PAYMENT_INTENT_FIXTURE)],
+        "has_more": false
+    });
+
+    mock_payment_intent_builder("GET", None)
+        .and(body_string_contains("status=awaiting_payment_method"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json_body))
+        .mount(&mock_server)
+        .await;
+
+    let config = mock_config(mock_server.uri())?;
+    let client = Client::with_config(config)?;
+    let params = PaymentIntentListParams::new().status(PaymentIntentStatus::AwaitingPaymentMethod);
+    let list = client.payment_intents().list(Some(params)).await?;
+
+    assert_eq!(list.data.len(), 1);
+    assert_eq!(
+        list.data[0].id,
+        PaymentIntentId::new("pi_FxmwbTkuQQb3qMBrgGiyNyzEFR7BKZVQ")
+    );
+    Ok(())
+}
@@ -2,8 +2,10 @@ mod support;
 
 use payrex::Error as PayrexError;
 use payrex::resources::payment_intents::PaymentIntentStatus;
-use payrex::resources::payments::{PaymentStatus, UpdatePayment};
-use payrex::types::{Metadata, PaymentId, PaymentIntentId, PaymentMethod, metadata};
+use payrex::resources::payments::{PaymentListParams, PaymentStatus, UpdatePayment};
+use payrex::types::{
+    Metadata, PaymentId, PaymentIntentId, PaymentMethod, RangeQuery, Timestamp, metadata,
+};
 use payrex::{
     Client, Config, ConfigBuilder,
     resources::payment_intents::CreatePaymentIntent,
@@ -88,3 +90,34 @@ async fn test_update_payment_ok() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_list_payments_ok() -> Result<()> {
+    let mock_server = MockServer::start().await;
+    let json_body = json!({
+        "resource": "list",
+        "data": [create_json_fixture(PAYMENT_FIXTURE)],
+        "has_more": false
+    });
+
+    mock_payment_builder("GET", None)
+        .and(body_string_contains("status=paid"))
+        .and(body_string_contains("created_at[gte]=1700000000"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json_body))
+        .mount(&mock_server)
+        .await;
+
+    let config = mock_config(mock_server.uri())?;
+    let client = Client::with_config(config)?;
+    let params = PaymentListParams::new()
+        .status(PaymentStatus::Paid)
+        .created_at(RangeQuery::new().gte(Timestamp::from_unix(1_700_000_000)));
+    let list = client.payments().list(Some(params)).await?;
+
+    assert_eq!(list.data.len(), 1);
+    assert_eq!(
+        list.data[0].id,
+        PaymentId::new("pay_M5zq1Mmun4bRZgyCXaBc4JLZm4mBtp2T")
+    );
+    Ok(())
+}
@@ -1,24 +1,23 @@
 mod support;
 
+use futures::TryStreamExt;
 use payrex::Client;
-use payrex::resources::payouts::PayoutTransactionType;
-use payrex::types::{PayoutId, PayoutTransactionId};
-use serde_json::Value;
+use payrex::resources::payouts::{PayoutListParams, PayoutStatus, PayoutTransactionType};
+use payrex::types::{ListParams, PayoutId, PayoutTransactionId};
+use serde_json::{Value, json};
 use wiremock::{
     Mock, MockBuilder, MockServer, ResponseTemplate,
-    matchers::{basic_auth, method, path},
+    matchers::{basic_auth, body_string_contains, method, path},
 };
 
 use crate::support::{Result, TEST_API_KEY, create_json_fixture, mock_config};
 
 const PAYOUT_FIXTURE: &str = include_str!("fixtures/payout.json");
+const SUCCESSFUL_PAYOUT_FIXTURE: &str = include_str!("fixtures/payout-successful.json");
 
 fn mock_payout_builder(method_str: &str, path_param: Option<&str>) -> MockBuilder {
     Mock::given(method(method_str))
-        .and(path(format!(
-            "/payouts{}/transactions",
-            path_param.unwrap_or("")
-        )))
+        .and(path(format!("/payouts{}", path_param.unwrap_or(""))))
         .and(basic_auth(TEST_API_KEY, ""))
 }
 
@@ -28,7 +27,7 @@ async fn test_list_payout_transactions_ok() -> Result<()> {
     let json_body = create_json_fixture(PAYOUT_FIXTURE);
     let payout_id = "po_eVG7pzEk7hLWFUpA6nj9pj4BQTk68kXb";
 
-    mock_payout_builder("GET", Some(format!("/{payout_id}").as_ref()))
+    mock_payout_builder("GET", Some(format!("/{payout_id}/transactions").as_ref()))
         .respond_with(ResponseTemplate::new(200).set_body_json(json_body.clone()))
         .mount(&mock_server)
         .await;
@@ -49,3 +48,65 @@ async fn test_list_payout_transactions_ok() -> Result<()> {
     assert_eq!(txn.transaction_type, PayoutTransactionType::Payment);
     Ok(())
 }
+
+#[tokio::test]
+async fn test_retrieve_payout_ok() -> Result<()> {
+    let mock_server = MockServer::start().await;
+    let json_body = create_json_fixture(SUCCESSFUL_PAYOUT_FIXTURE);
+    let payout_id = "po_eVG7pzEk7hLWFUpA6nj9pj4BQTk68kXb";
+
+    mock_payout_builder("GET", Some(format!("/{payout_id}").as_ref()))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json_body))
+        .mount(&mock_server)
+        .await;
+
+    let config = mock_config(mock_server.uri())?;
+    let client = Client::with_config(config)?;
+    let id = PayoutId::new(payout_id);
+    let payout = client.payouts().retrieve(&id).await?;
+
+    assert_eq!(payout.id, id);
+    assert_eq!(payout.amount, 52500);
+    assert_eq!(payout.net_amount, Some(51324));
+    assert_eq!(payout.status, PayoutStatus::Successful);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_list_payouts_with_transactions_ok() -> Result<()> {
+    let mock_server = MockServer::start().await;
+    let payout_id = "po_eVG7pzEk7hLWFUpA6nj9pj4BQTk68kXb";
+    let json_body = json!({
+        "resource": "list",
+        "data": [create_json_fixture(SUCCESSFUL_PAYOUT_FIXTURE)],
+        "has_more": false
+    });
+
+    mock_payout_builder("GET", None)
+        .and(body_string_contains("status=successful"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json_body))
+        .mount(&mock_server)
+        .await;
+
+    mock_payout_builder("GET", Some(format!("/{payout_id}/transactions").as_ref()))
+        .respond_with(ResponseTemplate::new(200).set_body_json(create_json_fixture(PAYOUT_FIXTURE)))
+        .mount(&mock_server)
+        .await;
+
+    let config = mock_config(mock_server.uri())?;
+    let client = Client::with_config(config)?;
+    let params = PayoutListParams::new().status(PayoutStatus::Successful);
+    let payouts: Vec<_> = client.payouts().list_auto(params).try_collect().await?;
+
+    assert_eq!(payouts.len(), 1);
+
+    let transactions: Vec<_> = client
+        .payouts()
+        .list_transactions_auto(&payouts[0].id, ListParams::new())
+        .try_collect()
+        .await?;
+
+    assert_eq!(transactions.len(), 1);
+    assert_eq!(transactions[0].net_amount, 51324);
+    Ok(())
+}
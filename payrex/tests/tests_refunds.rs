@@ -1,7 +1,12 @@
 mod support;
 
 use payrex::Client;
-use payrex::resources::refunds::{CreateRefund, RefundReason, RefundStatus, UpdateRefund};
+use payrex::Error as PayrexError;
+use payrex::resources::payments::{Payment, PaymentStatus};
+use payrex::resources::refunds::{
+    CreateRefund, Refund, RefundListParams, RefundReason, RefundStatus, UpdateRefund,
+    refundable_amount,
+};
 use payrex::types::{Currency, Metadata, PaymentId, RefundId};
 use serde_json::{Value, json};
 use wiremock::{
@@ -12,6 +17,7 @@ use wiremock::{
 use crate::support::{Result, TEST_API_KEY, create_json_fixture, mock_config};
 
 const REFUND_FIXTURE: &str = include_str!("fixtures/refund.json");
+const PAYMENT_FIXTURE: &str = include_str!("fixtures/payment.json");
 
 fn mock_refund_builder(method_str: &str, path_param: Option<&str>) -> MockBuilder {
     Mock::given(method(method_str))
@@ -81,3 +87,177 @@ async fn test_update_refund_ok() -> Result<()> {
     assert_eq!(response.metadata, Some(metadata));
     Ok(())
 }
+
+#[tokio::test]
+async fn test_retrieve_refund_ok() -> Result<()> {
+    let mock_server = MockServer::start().await;
+    let json_body = create_json_fixture(REFUND_FIXTURE);
+    let ref_id = "re_o9yzYyP9iSE1nrvuE8nNxAPkMpmR5GS6";
+    let id = RefundId::new(ref_id);
+
+    mock_refund_builder("GET", Some(format!("/{ref_id}").as_ref()))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json_body))
+        .mount(&mock_server)
+        .await;
+
+    let config = mock_config(mock_server.uri())?;
+    let client = Client::with_config(config)?;
+    let response = client.refunds().retrieve(&id).await?;
+
+    assert_eq!(response.id, id);
+    assert_eq!(response.amount, 167);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_list_refunds_ok() -> Result<()> {
+    let mock_server = MockServer::start().await;
+    let json_body = json!({
+        "resource": "list",
+        "data": [create_json_fixture(REFUND_FIXTURE)],
+        "has_more": false
+    });
+
+    mock_refund_builder("GET", None)
+        .and(body_string_contains(
+            "payment_id=pay_M5zq1Mmun4bRZgyCXaBc4JLZm4mBtp2T",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json_body))
+        .mount(&mock_server)
+        .await;
+
+    let config = mock_config(mock_server.uri())?;
+    let client = Client::with_config(config)?;
+    let params =
+        RefundListParams::new().payment_id(PaymentId::new("pay_M5zq1Mmun4bRZgyCXaBc4JLZm4mBtp2T"));
+    let list = client.refunds().list(Some(params)).await?;
+
+    assert_eq!(list.data.len(), 1);
+    assert_eq!(
+        list.data[0].id,
+        RefundId::new("re_o9yzYyP9iSE1nrvuE8nNxAPkMpmR5GS6")
+    );
+    Ok(())
+}
+
+#[test]
+fn test_refundable_amount_ignores_failed_refunds() -> Result<()> {
+    let mut payment: Payment = serde_json::from_value(create_json_fixture(PAYMENT_FIXTURE))?;
+    payment.amount_refunded = 0;
+    let succeeded: Refund = serde_json::from_value(create_json_fixture(REFUND_FIXTURE))?;
+
+    let mut failed = succeeded.clone();
+    failed.status = RefundStatus::Failed;
+
+    let mut unknown = succeeded.clone();
+    unknown.status = RefundStatus::Unknown("reversed".to_string());
+
+    let mut other_payment = succeeded.clone();
+    other_payment.payment_id = PaymentId::new("pay_other");
+
+    let refunds = [succeeded, failed, unknown, other_payment];
+    assert_eq!(refundable_amount(&payment, &refunds), payment.amount - 167);
+    assert_eq!(refundable_amount(&payment, &[]), payment.amount);
+    Ok(())
+}
+
+#[test]
+fn test_refundable_amount_uses_amount_refunded() -> Result<()> {
+    let mut payment: Payment = serde_json::from_value(create_json_fixture(PAYMENT_FIXTURE))?;
+    let refund: Refund = serde_json::from_value(create_json_fixture(REFUND_FIXTURE))?;
+
+    assert_eq!(payment.amount_refunded, 525);
+    assert_eq!(refundable_amount(&payment, &[]), payment.amount - 525);
+    assert_eq!(
+        refundable_amount(&payment, std::slice::from_ref(&refund)),
+        payment.amount - 525
+    );
+
+    payment.amount_refunded = 100;
+    assert_eq!(refundable_amount(&payment, &[refund]), payment.amount - 167);
+
+    payment.status = PaymentStatus::Failed;
+    assert_eq!(refundable_amount(&payment, &[]), 0);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_create_refund_checked_rejects_over_refund() -> Result<()> {
+    let mock_server = MockServer::start().await;
+    let payment: Payment = serde_json::from_value(create_json_fixture(PAYMENT_FIXTURE))?;
+    let json_body = json!({
+        "resource": "list",
+        "data": [create_json_fixture(REFUND_FIXTURE)],
+        "has_more": false
+    });
+
+    mock_refund_builder("GET", None)
+        .respond_with(ResponseTemplate::new(200).set_body_json(json_body))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    mock_refund_builder("POST", None)
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let config = mock_config(mock_server.uri())?;
+    let client = Client::with_config(config)?;
+    let refundable = payment.amount - payment.amount_refunded;
+    let params = CreateRefund::new(
+        payment.id.clone(),
+        RefundReason::ProductOutOfStock,
+        refundable + 1,
+        Currency::PHP,
+    );
+    let err = client
+        .refunds()
+        .create_checked(&payment, params)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(
+        err,
+        PayrexError::RefundAmountExceeded { requested, refundable: left }
+            if requested == refundable + 1 && left == refundable
+    ));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_create_refund_checked_rejects_unpaid_payment() -> Result<()> {
+    let mock_server = MockServer::start().await;
+    let mut payment: Payment = serde_json::from_value(create_json_fixture(PAYMENT_FIXTURE))?;
+    payment.status = PaymentStatus::Failed;
+
+    mock_refund_builder("GET", None)
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    mock_refund_builder("POST", None)
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let config = mock_config(mock_server.uri())?;
+    let client = Client::with_config(config)?;
+    let params = CreateRefund::new(
+        payment.id.clone(),
+        RefundReason::ProductOutOfStock,
+        100,
+        Currency::PHP,
+    );
+    let err = client
+        .refunds()
+        .create_checked(&payment, params)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, PayrexError::InvalidRequest(_)));
+    Ok(())
+}
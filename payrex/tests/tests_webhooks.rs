@@ -1,13 +1,15 @@
 mod support;
 
+use futures::TryStreamExt;
 use payrex::Client;
 use payrex::resources::webhooks::{CreateWebhook, UpdateWebhook, WebhookListParams, WebhookStatus};
-use payrex::types::WebhookId;
+use payrex::resources::{Listable, Retrievable};
 use payrex::types::event::{BillingStatementEvent, CheckoutSessionEvent, EventType};
+use payrex::types::{Resource, WebhookId};
 use serde_json::{Value, json};
 use wiremock::{
     Mock, MockBuilder, MockServer, ResponseTemplate,
-    matchers::{basic_auth, method, path, query_param},
+    matchers::{basic_auth, body_string_contains, method, path, query_param},
 };
 
 use crate::support::{Result, TEST_API_KEY, create_json_fixture, mock_config};
@@ -173,3 +175,76 @@ async fn test_enable_disable_webhook_ok() -> Result<()> {
     assert_eq!(resp_disable.id, id);
     Ok(())
 }
+
+#[tokio::test]
+async fn test_list_auto_webhook_ok() -> Result<()> {
+    let mock_server = MockServer::start().await;
+    let mut first_page = create_json_fixture(WEBHOOK_LIST_FIXTURE);
+    let mut second_page = first_page.clone();
+    let first_id = "wh_pDQvyHKPJ5J5SahAjDYLG2u6TUxvB6GH";
+    let second_id = "wh_9sQm5bYx3kP2dLr7TnWc4HvZ8eFjGa1U";
+
+    first_page["has_more"] = Value::Bool(true);
+    second_page["data"][0]["id"] = Value::String(second_id.to_string());
+
+    mock_webhook_builder("GET", None)
+        .and(body_string_contains(format!("after={first_id}")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(second_page))
+        .with_priority(1)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    mock_webhook_builder("GET", None)
+        .respond_with(ResponseTemplate::new(200).set_body_json(first_page))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let config = mock_config(mock_server.uri())?;
+    let client = Client::with_config(config)?;
+    let webhooks: Vec<_> = client
+        .webhooks()
+        .list_auto(WebhookListParams::new())
+        .try_collect()
+        .await?;
+
+    assert_eq!(webhooks.len(), 2);
+    assert_eq!(webhooks[0].id, WebhookId::new(first_id));
+    assert_eq!(webhooks[1].id, WebhookId::new(second_id));
+    Ok(())
+}
+
+async fn first_listed_item<C, T>(client: &C) -> payrex::Result<T>
+where
+    C: Listable<Item = T> + Retrievable<Item = T, Id = T::Id>,
+    T: Resource,
+{
+    let list = Listable::list(client, Default::default()).await?;
+    Retrievable::retrieve(client, list.data[0].id()).await
+}
+
+#[tokio::test]
+async fn test_generic_list_and_retrieve_webhook_ok() -> Result<()> {
+    let mock_server = MockServer::start().await;
+    let wh_id = "wh_pDQvyHKPJ5J5SahAjDYLG2u6TUxvB6GH";
+
+    mock_webhook_builder("GET", None)
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(create_json_fixture(WEBHOOK_LIST_FIXTURE)),
+        )
+        .mount(&mock_server)
+        .await;
+    mock_webhook_builder("GET", Some(format!("/{wh_id}").as_ref()))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(create_json_fixture(WEBHOOK_FIXTURE)),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let config = mock_config(mock_server.uri())?;
+    let client = Client::with_config(config)?;
+    let webhook = first_listed_item(&client.webhooks()).await?;
+
+    assert_eq!(webhook.id, WebhookId::new(wh_id));
+    Ok(())
+}
@@ -1,7 +1,7 @@
 use darling::FromMeta;
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::{format_ident, quote};
-use syn::{Field, parse_quote, punctuated::Punctuated, token::Comma};
+use syn::{Field, LitStr, parse_quote, punctuated::Punctuated, token::Comma};
 
 use crate::utils::is_type;
 
@@ -15,6 +15,7 @@ pub(crate) struct PayrexAttrs {
     pub description: Option<String>,
     pub currency: Option<bool>,
     pub optional: bool,
+    pub resource: bool,
 }
 
 pub(crate) struct ParsedPayrexAttrs {
@@ -137,6 +138,39 @@ If the description is not modified, the default value is "Payment for Billing St
         }
     }
 
+    pub fn add_resource(&mut self) {
+        if self.attrs.resource {
+            let deserialize_with = LitStr::new(
+                &format!(
+                    "crate::types::common::deserialize_object_type::<{}, _>",
+                    self.ident
+                ),
+                Span::call_site(),
+            );
+
+            self.fields.push(parse_quote! {
+                /// The type of the resource. Deserializing a payload of a different type fails.
+                #[serde(deserialize_with = #deserialize_with)]
+                pub resource: ObjectType
+            });
+        }
+    }
+
+    pub fn add_extra(&mut self) {
+        if self.attrs.resource {
+            self.fields.push(Self::extra_field());
+        }
+    }
+
+    fn extra_field() -> Field {
+        parse_quote! {
+            /// Fields returned by PayRex that this version of the SDK does not model yet. They are
+            /// kept as-is so they can be read and serialized back without loss.
+            #[serde(flatten)]
+            pub extra: ::serde_json::Map<String, ::serde_json::Value>
+        }
+    }
+
     pub fn add_currency(&mut self) {
         if let Some(is_optional) = self.attrs.currency {
             let ty = if is_optional {
@@ -187,6 +221,12 @@ impl ParsedPayrexAttrs {
         if self.attrs.optional {
             let optional_ident = format_ident!("Optional{}", self.ident);
             let optional_fields = self.gen_optional_fields();
+            let extra_field = if self.attrs.resource {
+                let field = Self::extra_field();
+                quote! { , #field }
+            } else {
+                quote! {}
+            };
             let docs = format!(
                 "Optional variant for {}. This is only used for responses in billing statements API.",
                 self.ident
@@ -195,7 +235,7 @@ impl ParsedPayrexAttrs {
                 #[doc = #docs]
                 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
                 pub struct #optional_ident {
-                    #optional_fields
+                    #optional_fields #extra_field
                 }
             });
         }
@@ -48,6 +48,7 @@ pub fn payrex_attr(attr: TokenStream, item: TokenStream) -> TokenStream {
     let parsed_opts: ParsedPayrexAttrs = opts.into();
     let mut opts = parsed_opts.set_fields(fields).set_ident(ident.clone());
 
+    opts.add_resource();
     opts.add_amount();
     opts.add_metadata();
     opts.add_description();
@@ -55,6 +56,7 @@ pub fn payrex_attr(attr: TokenStream, item: TokenStream) -> TokenStream {
     opts.add_timestamp();
     opts.add_currency();
     opts.add_optional_struct();
+    opts.add_extra();
 
     *fields = opts.fields;
     let extra_tokens = opts.extra_tokens;